@@ -6,24 +6,20 @@
 
 use criterion::{criterion_group, criterion_main, Criterion};
 
+use sqrid::postrait::PosT;
+
 fn mov_mutual() {
     type Pos = sqrid::Pos<256, 257>;
     for pos in Pos::iter() {
         for dir in sqrid::Dir::iter::<true>() {
             if let Ok(pos2) = pos + dir {
-                let found = sqrid::Dir::iter::<true>()
-                    .filter(|dir| pos2 + *dir == Ok(pos))
-                    .next()
-                    .is_some();
+                let found = sqrid::Dir::iter::<true>().any(|dir| pos2 + dir == Ok(pos));
                 assert!(found);
             }
         }
         for dir in sqrid::Dir::iter::<false>() {
             if let Ok(pos2) = pos + dir {
-                let found = sqrid::Dir::iter::<false>()
-                    .filter(|dir| pos2 + *dir == Ok(pos))
-                    .next()
-                    .is_some();
+                let found = sqrid::Dir::iter::<false>().any(|dir| pos2 + dir == Ok(pos));
                 assert!(found);
             }
         }
@@ -32,7 +28,7 @@ fn mov_mutual() {
 
 fn grid_index() {
     type Pos = sqrid::Pos<256, 257>;
-    type Grid = sqrid::Grid<usize, 256, 257, { 256 * 257 }>;
+    type Grid = sqrid::Grid<usize, Pos, { 257 * 258 }>;
     let mut g = Grid::default();
     for pos in Pos::iter() {
         g[pos] = pos.to_usize();
@@ -42,7 +38,7 @@ fn grid_index() {
     }
 }
 
-type Astar = sqrid::sqrid_create!(30, 15, false);
+type Astar = sqrid::sqrid_create!(29, 14, false);
 type Pos = sqrid::pos_create!(Astar);
 type Gridbool = sqrid::gridbool_create!(Astar);
 
@@ -100,7 +96,64 @@ fn astar_data() -> Vec<(Pos, Pos, Gridbool)> {
 fn astar_search(pars: &[(Pos, Pos, Gridbool)]) {
     for par in pars {
         let _ = Astar::astar_path(
-            |pos, dir| sqrid::mov_eval(pos, dir).filter(|pos| !par.2.get(pos)),
+            |pos, dir| sqrid::pos_dir_add_ok(pos, dir).filter(|pos| !par.2.get(pos)),
+            &par.0,
+            &par.1,
+        );
+    }
+}
+
+/// Digit-like step cost, 1..=9, derived from the destination
+fn digit_cost(pos: Pos) -> usize {
+    1 + (pos.x() as usize * 7 + pos.y() as usize * 3) % 9
+}
+
+fn ucs_search(pars: &[(Pos, Pos, Gridbool)]) {
+    for par in pars {
+        let _ = Astar::ucs_path(
+            |pos, dir| {
+                let next = sqrid::pos_dir_add_ok(pos, dir).filter(|pos| !par.2.get(pos))?;
+                Some((next, digit_cost(next)))
+            },
+            &par.0,
+            &par.1,
+        );
+    }
+}
+
+fn dial_search(pars: &[(Pos, Pos, Gridbool)]) {
+    for par in pars {
+        let _ = Astar::dial_path(
+            |pos, dir| {
+                let next = sqrid::pos_dir_add_ok(pos, dir).filter(|pos| !par.2.get(pos))?;
+                Some((next, digit_cost(next)))
+            },
+            &par.0,
+            &par.1,
+        );
+    }
+}
+
+fn ucs01_search(pars: &[(Pos, Pos, Gridbool)]) {
+    for par in pars {
+        let _ = Astar::ucs_path(
+            |pos, dir| {
+                let next = sqrid::pos_dir_add_ok(pos, dir)?;
+                Some((next, par.2.get(&next).into()))
+            },
+            &par.0,
+            &par.1,
+        );
+    }
+}
+
+fn bfs01_search(pars: &[(Pos, Pos, Gridbool)]) {
+    for par in pars {
+        let _ = Astar::bfs01_path(
+            |pos, dir| {
+                let next = sqrid::pos_dir_add_ok(pos, dir)?;
+                Some((next, par.2.get(&next).into()))
+            },
             &par.0,
             &par.1,
         );
@@ -108,10 +161,14 @@ fn astar_search(pars: &[(Pos, Pos, Gridbool)]) {
 }
 
 fn criterion_benchmark(c: &mut Criterion) {
-    c.bench_function("mov_mutual", |b| b.iter(|| mov_mutual()));
-    c.bench_function("grid_index", |b| b.iter(|| grid_index()));
+    c.bench_function("mov_mutual", |b| b.iter(mov_mutual));
+    c.bench_function("grid_index", |b| b.iter(grid_index));
     let data = astar_data();
     c.bench_function("astar_search", |b| b.iter(|| astar_search(&data)));
+    c.bench_function("ucs_search", |b| b.iter(|| ucs_search(&data)));
+    c.bench_function("dial_search", |b| b.iter(|| dial_search(&data)));
+    c.bench_function("ucs01_search", |b| b.iter(|| ucs01_search(&data)));
+    c.bench_function("bfs01_search", |b| b.iter(|| bfs01_search(&data)));
 }
 
 criterion_group!(benches, criterion_benchmark);
@@ -2,12 +2,13 @@
 // This file is subject to the terms and conditions defined in
 // file 'LICENSE', which is part of this source code package.
 
+#![cfg_attr(not(feature = "std"), no_std)]
 #![warn(rust_2018_idioms)]
 #![warn(missing_docs)]
 #![deny(rustdoc::broken_intra_doc_links)]
 
 //! *sqrid* provides square grid coordinates and related operations,
-//! in a crate with zero dependencies.
+//! in a crate with zero dependencies by default.
 //!
 //! It's easier to explain the features of this crate in terms of the
 //! types it provides:
@@ -20,23 +21,49 @@
 //!   which can be `None` if the result is outside the grid.
 //! - [`Grid`]: a `Pos`-indexed array.
 //! - [`Gridbool`]: a bitmap-backed `Pos`-indexed grid of booleans.
+//! - [`InfiniteGrid`]: a grid without bounds, indexed by signed
+//!   `(i32, i32)` coordinates and stored in chunks allocated on demand.
 //! - [`Sqrid`]: "factory" type that acts as an entry point to the
 //!   fundamental types below and to algorithms.
 //!
 //! We also have traits that generalize `Grid` and `Gridbool`:
 //! - [`MapPos`]: trait that maps `Pos` to parameterized items;
-//!   it's implemented by `Grid`, and some `HashMap`/`BTreeMap` based types.
+//!   it's implemented by `Grid`, `Box<Grid>` (for grids too large for
+//!   the stack), and some `HashMap`/`BTreeMap` based types.
 //! - [`SetPos`]: trait that maps each `Pos` to a bool; it's implemented
-//!   by `Gridbool`, `HashSet<Pos>` and `BTreeSet<Pos>`.
+//!   by `Gridbool`, `Box<Gridbool>`, `HashSet<Pos>` and `BTreeSet<Pos>`.
 //!
 //! We then use these generalization to implement some grid algorithms:
 //! - [`bf`]: breadth-first iteration and search.
 //! - [`astar`]: A* search that takes a destination `Pos`.
 //! - [`ucs`]: uniform-cost search.
+//! - [`bfs01`]: 0-1 breadth-first search, for step costs of `0` or `1`.
+//! - [`dial`]: uniform-cost search with a bucket queue, for small integer
+//!   step costs.
+//! - [`flowfield`]: flow fields, with the direction towards a goal from
+//!   every position, for many agents heading to the same place.
+//! - [`maze`]: maze generation with several algorithms, seeded by a
+//!   user-provided random number generator.
+//! - [`automaton`]: cellular automata on grids, including a bit-parallel
+//!   Game of Life for `Gridbool`.
+//! - [`cycle`]: cycle detection, to extrapolate the result of applying a
+//!   transformation a huge number of times.
+//! - [`symmetry`]: the 8 rotations and reflections of the square, with
+//!   canonical forms to deduplicate symmetric states.
+//! - [`shape`]: polyominoes and other piece shapes, with their orientations,
+//!   that can be placed on a `Gridbool` and dropped until they collide.
+//! - [`polygon`]: area of closed loops traced by `Dir` paths, using the
+//!   shoelace formula and Pick's theorem, and their interior cells.
+//! - [`prefixsum`]: summed-area tables, with constant-time sums of the
+//!   values of `Grid` rectangles, or of the `true` cells of `Gridbool`.
 //!
 //! All basic types have the standard `iter`, `iter_mut`, `extend`,
 //! `as_ref`, and conversion operations that should be expected.
 //!
+//! `Grid` and `Gridbool` can also be exported to and imported from
+//! PBM/PGM/PPM images, see [`netpbm`], which is handy for grids too
+//! large to read in a terminal.
+//!
 //! # Fundamental types
 //!
 //! ## `Pos`: absolute coordinates, position
@@ -75,15 +102,34 @@
 //! argument that specifies whether the intercardinal directions
 //! (`NE`, `SE`, `SW`, `NW`) should be considered.
 //!
+//! ## `Offset`: signed vectors
+//!
+//! An [`Offset`] is a signed `(dx, dy)` vector. Subtracting two [`Pos`]
+//! gives us the [`Offset`] between them, and adding an [`Offset`] to a
+//! [`Pos`] returns a `Result` with the new position. Multiplying a
+//! [`Dir`] by a scalar also creates an [`Offset`]:
+//!
+//! ```rust
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! type Pos = sqrid::Pos<6, 7>;
+//!
+//! let pos = Pos::new(1, 3)?;
+//! let offset = sqrid::Dir::E * 4;
+//! assert_eq!(pos + offset, Pos::new(5, 3));
+//! assert_eq!(Pos::new(5, 3)? - pos, offset);
+//! # Ok(()) }
+//! ```
+//!
 //! ## `Grid`: a `Pos`-indexed array
 //!
 //! A [`Grid`] is a generic array that can be indexed by a [`Pos`].
 //!
 //! We can create the type from a suitable [`Sqrid`] type by using the
 //! [`grid_create`] macro. We can then interact with specific lines
-//! with [`Grid::line`] and [`Grid::line_mut`], or with the whole
-//! underlying array with `as_ref` (see [`std::convert::AsRef`]) and
-//! `as_mut` (see [`std::convert::AsMut`]).
+//! with [`Grid::line`] and [`Grid::line_mut`], with columns with
+//! [`Grid::column`] and [`Grid::column_mut`], or with the whole
+//! underlying array with `as_ref` (see [`core::convert::AsRef`]) and
+//! `as_mut` (see [`core::convert::AsMut`]).
 //!
 //! Usage example:
 //!
@@ -114,6 +160,12 @@
 //!     println!("[{}] = {}", pos, i);
 //! }
 //!
+//! // Create grids from a function of the position, and transform
+//! // them with `map` and `zip_with` without going through iterators:
+//! let gridx = Grid::from_fn(|pos| pos.x() as i32);
+//! let gridsum = gridnums.zip_with(&gridx, |a, b| a + b).map(|v| v * 2);
+//! assert_eq!(gridsum[Pos::BOTTOM_RIGHT], 2 * (80 + 2));
+//!
 //! // And we can always use `as_ref` or `as_mut` to interact with the
 //! // inner array directly. To reverse it, for example, with the
 //! // [`std::slice::reverse`] function:
@@ -277,6 +329,55 @@
 //!     println!("path: {:?}", path);
 //! }
 //! ```
+//!
+//! When the step costs are small integers, [`Sqrid::bfs01_path`] (costs
+//! `0` or `1`) and [`Sqrid::dial_path`] (costs such as `1..=9`) take the
+//! same arguments as `ucs_path` and avoid the overhead of the binary heap.
+//!
+//! When many agents share the same goal, [`Sqrid::flow_field`] runs a
+//! single reverse UCS from the goal and returns a
+//! [`flowfield::FlowField`] with the direction and the cost to the goal
+//! from every position.
+//!
+//! ## Search limits
+//!
+//! [`Sqrid::bfs_path_limits`], [`Sqrid::astar_path_limits`] and
+//! [`Sqrid::ucs_path_limits`] take an extra [`SearchLimits`] argument
+//! that can stop the search at a maximum cost, after a maximum number of
+//! expanded positions, or when a cancellation callback returns `true`.
+//! They return [`LimitsError::BudgetExceeded`] with the partial "came
+//! from" map of the positions visited so far in that case, which
+//! converts into [`Error::BudgetExceeded`] when the map is not needed.
+//!
+//! The search iterators can also collect [`SearchStats`] - number of
+//! expanded positions, maximum frontier size, re-pushes and elapsed time -
+//! and call a [`SearchObserver`] on each expanded position, via their
+//! `with_stats` and `with_observer` methods.
+//!
+//! # Cargo features
+//!
+//! - `std` (default): without it, *sqrid* is `no_std` and only needs
+//!   `alloc`. The `HashMap`-based searches (`*_hash`) and
+#![cfg_attr(
+    feature = "std",
+    doc = "  [`find_cycle`] require `std`, the `BTreeMap`-based ones (`*_btree`)"
+)]
+#![cfg_attr(
+    not(feature = "std"),
+    doc = "  `find_cycle` require `std`, the `BTreeMap`-based ones (`*_btree`)"
+)]
+//!   can be used instead. [`SearchStats::elapsed`] stays zero without
+//!   `std`.
+//! - `serde`: implements `Serialize` and `Deserialize` for the bounded
+//!   integers, `Pos`, `Dir`, `Grid` and `Gridbool`. Grids are serialized
+//!   as sequences of rows, and `Gridbool` rows as ASCII strings of `#`
+//!   and `.`; deserialization checks the bounds and dimensions.
+//! - `rayon`: adds the `parallel` module, with `Grid::par_iter_pos`,
+//!   `Grid::par_map`, connected components labeling and many-to-many
+//!   BFS/UCS distances that run the independent searches concurrently,
+//!   all using [rayon](https://crates.io/crates/rayon). Implies `std`.
+
+extern crate alloc;
 
 mod sqrid;
 pub use self::sqrid::*;
@@ -27,7 +27,7 @@
 //! several generic parameters. An easier alternative is provided by the wrappers plugged into
 //! the [`Sqrid`] type:
 //! - [`Sqrid::astar_path_grid`]
-//! - [`Sqrid::astar_path_hash`]
+#![cfg_attr(feature = "std", doc = " - [`Sqrid::astar_path_hash`]")]
 //! - [`Sqrid::astar_path_btree`]
 //! - [`Sqrid::astar_path`]: alias for `astar_path_grid`.
 //!
@@ -44,21 +44,36 @@
 //! }
 //! ```
 
-use std::cmp::Reverse;
-use std::collections;
-use std::collections::BinaryHeap;
+use alloc::boxed::Box;
+use alloc::collections;
+use alloc::collections::BinaryHeap;
+use alloc::vec::Vec;
+use core::cmp::Reverse;
 
 use super::camefrom_into_path;
 use super::postrait::PosT;
+use super::stats::Timer;
+use super::ucs::Cost;
 use super::Dir;
 use super::Error;
 use super::Grid;
+use super::LimitsError;
+use super::LimitsErrorGrid;
 use super::MapPos;
+use super::SearchLimits;
+use super::SearchObserver;
+use super::SearchStats;
 use super::Sqrid;
 
 /* AstarIterator **************************************************************/
 
 /// Internal A* iterator
+///
+/// Yields the positions as `(pos, dir, cost)`, with `cost` being the
+/// cost of the path from the origin.
+///
+/// Can optionally collect [`SearchStats`] and call a [`SearchObserver`] on each expanded
+/// position; see [`stats`](crate::stats).
 #[derive(Debug, Clone)]
 pub struct AstarIterator<
     F,
@@ -67,10 +82,13 @@ pub struct AstarIterator<
     const D: bool,
     const WORDS: usize,
     const SIZE: usize,
+    Obs = (),
 > {
     cost: MapPosUsize,
     frontier: BinaryHeap<(Reverse<usize>, (P, Dir))>,
     go: F,
+    stats: Option<SearchStats>,
+    observer: Obs,
     dest: P,
 }
 
@@ -91,39 +109,124 @@ impl<F, MapPosUsize, P: PosT, const D: bool, const WORDS: usize, const SIZE: usi
             cost: MapPosUsize::new(usize::MAX),
             frontier: BinaryHeap::default(),
             go,
+            stats: None,
+            observer: (),
             dest: *dest,
         };
-        it.frontier.push((Reverse(0), (*orig, Dir::default())));
+        it.frontier
+            .push((Reverse(orig.manhattan(dest)), (*orig, Dir::default())));
         it.cost.set(*orig, 0);
         it
     }
 }
 
-impl<F, MapPosUsize, P: PosT, const D: bool, const WORDS: usize, const SIZE: usize> Iterator
-    for AstarIterator<F, MapPosUsize, P, D, WORDS, SIZE>
+impl<F, MapPosUsize, P: PosT, const D: bool, const WORDS: usize, const SIZE: usize, Obs>
+    AstarIterator<F, MapPosUsize, P, D, WORDS, SIZE, Obs>
+where
+    MapPosUsize: MapPos<usize, P, WORDS, SIZE>,
+{
+    /// Return the lowest cost found so far to reach the provided position
+    ///
+    /// For the positions already yielded, this is the cost of the
+    /// path to them.
+    pub fn cost(&self, pos: &P) -> Cost {
+        *self.cost.get(pos)
+    }
+
+    /// Enable the collection of [`SearchStats`]
+    pub fn with_stats(mut self) -> Self {
+        self.stats = Some(SearchStats::default());
+        self
+    }
+
+    /// Return the [`SearchStats`] collected so far, if enabled by
+    /// [`AstarIterator::with_stats`]
+    pub fn stats(&self) -> Option<&SearchStats> {
+        self.stats.as_ref()
+    }
+
+    /// Set the [`SearchObserver`] called with each position and its
+    /// cost when they are expanded
+    pub fn with_observer<O>(
+        self,
+        observer: O,
+    ) -> AstarIterator<F, MapPosUsize, P, D, WORDS, SIZE, O>
+    where
+        O: SearchObserver<P>,
+    {
+        AstarIterator {
+            cost: self.cost,
+            frontier: self.frontier,
+            go: self.go,
+            dest: self.dest,
+            stats: self.stats,
+            observer,
+        }
+    }
+}
+
+impl<F, MapPosUsize, P: PosT, const D: bool, const WORDS: usize, const SIZE: usize, Obs> Iterator
+    for AstarIterator<F, MapPosUsize, P, D, WORDS, SIZE, Obs>
 where
     F: Fn(P, Dir) -> Option<P>,
     MapPosUsize: MapPos<usize, P, WORDS, SIZE>,
+    Obs: SearchObserver<P>,
     P: Ord,
     P: Copy,
 {
-    type Item = (P, Dir);
+    type Item = (P, Dir, Cost);
     fn next(&mut self) -> Option<Self::Item> {
-        if let Some((_, mov)) = self.frontier.pop() {
-            let pos = mov.0;
-            for dir in Dir::iter::<D>() {
-                let newcost = self.cost.get(&pos) + 1;
-                if let Some(next_pos) = (self.go)(pos, dir) {
-                    if newcost < *self.cost.get(&next_pos) {
-                        self.cost.set(next_pos, newcost);
-                        let priority = Reverse(newcost + next_pos.manhattan(&self.dest));
-                        self.frontier.push((priority, (next_pos, -dir)));
-                    }
+        let start = self.stats.is_some().then(Timer::start);
+        let (pos, dir, cost) = self.pop()?;
+        self.expand(pos, cost);
+        if let (Some(stats), Some(start)) = (&mut self.stats, start) {
+            stats.elapsed += start.elapsed();
+        }
+        Some((pos, dir, cost))
+    }
+}
+
+impl<F, MapPosUsize, P: PosT, const D: bool, const WORDS: usize, const SIZE: usize, Obs>
+    AstarIterator<F, MapPosUsize, P, D, WORDS, SIZE, Obs>
+where
+    F: Fn(P, Dir) -> Option<P>,
+    MapPosUsize: MapPos<usize, P, WORDS, SIZE>,
+    Obs: SearchObserver<P>,
+    P: Ord,
+    P: Copy,
+{
+    /// Pop the next position from the frontier, without expanding it
+    fn pop(&mut self) -> Option<(P, Dir, Cost)> {
+        loop {
+            let (Reverse(priority), (pos, dir)) = self.frontier.pop()?;
+            let cost = *self.cost.get(&pos);
+            // Skip stale entries, superseded by a cheaper path:
+            if priority == cost + pos.manhattan(&self.dest) {
+                return Some((pos, dir, cost));
+            }
+        }
+    }
+
+    /// Expand a position, pushing its neighbors to the frontier
+    fn expand(&mut self, pos: P, cost: Cost) {
+        self.observer.on_expand(pos, cost);
+        let mut repushed = 0;
+        for dir in Dir::iter::<D>() {
+            let newcost = cost + 1;
+            if let Some(next_pos) = (self.go)(pos, dir) {
+                let oldcost = *self.cost.get(&next_pos);
+                if newcost < oldcost {
+                    self.cost.set(next_pos, newcost);
+                    let priority = Reverse(newcost + next_pos.manhattan(&self.dest));
+                    self.frontier.push((priority, (next_pos, -dir)));
+                    repushed += usize::from(oldcost != usize::MAX);
                 }
             }
-            Some(mov)
-        } else {
-            None
+        }
+        if let Some(stats) = &mut self.stats {
+            stats.expanded += 1;
+            stats.repushed += repushed;
+            stats.max_frontier = stats.max_frontier.max(self.frontier.len());
         }
     }
 }
@@ -154,8 +257,8 @@ where
     P: Ord,
     P: Copy,
 {
-    let mut from = MapPosDir::default();
-    for (pos, dir) in AstarIterator::<F, MapPosUsize, P, D, WORDS, SIZE>::new(go, orig, dest) {
+    let mut from = MapPosDir::new(None);
+    for (pos, dir, _) in AstarIterator::<F, MapPosUsize, P, D, WORDS, SIZE>::new(go, orig, dest) {
         from.set(pos, Some(dir));
         if pos == *dest {
             return Ok(from);
@@ -188,7 +291,7 @@ where
     MapPosDir: MapPos<Option<Dir>, P, WORDS, SIZE> + Default,
     MapPosUsize: MapPos<usize, P, WORDS, SIZE> + Default,
     P: PosT,
-    P: std::ops::Add<Dir, Output = Result<P, Error>>,
+    P: core::ops::Add<Dir, Output = Result<P, Error>>,
     P: Ord,
     P: Copy,
 {
@@ -196,9 +299,97 @@ where
     camefrom_into_path(mapmov, orig, dest)
 }
 
+/// Make an A* search that can be stopped by [`SearchLimits`], return the "came from"
+/// direction [`MapPos`]
+///
+/// Returns [`LimitsError::BudgetExceeded`] with the positions visited so far when a limit
+/// is reached before the destination.
+///
+/// Generic interface over types that implement [`MapPos`] for [`Dir`] and `usize`
+pub fn search_mapmov_limits<
+    F,
+    MapPosDir,
+    MapPosUsize,
+    P,
+    const D: bool,
+    const WORDS: usize,
+    const SIZE: usize,
+>(
+    go: F,
+    orig: &P,
+    dest: &P,
+    limits: &SearchLimits<'_>,
+) -> Result<MapPosDir, LimitsError<MapPosDir>>
+where
+    F: Fn(P, Dir) -> Option<P>,
+    MapPosDir: MapPos<Option<Dir>, P, WORDS, SIZE> + Default,
+    MapPosUsize: MapPos<usize, P, WORDS, SIZE> + Default,
+    P: PosT,
+    P: Ord,
+    P: Copy,
+{
+    let mut from = MapPosDir::new(None);
+    let mut it = AstarIterator::<F, MapPosUsize, P, D, WORDS, SIZE>::new(go, orig, dest);
+    let mut expanded = 0;
+    while let Some((pos, dir, cost)) = it.pop() {
+        if limits.cost_exceeded(cost) {
+            return Err(LimitsError::BudgetExceeded(from));
+        }
+        from.set(pos, Some(dir));
+        if pos == *dest {
+            return Ok(from);
+        }
+        if limits.expansion_exceeded(expanded, 1) {
+            return Err(LimitsError::BudgetExceeded(from));
+        }
+        it.expand(pos, cost);
+        expanded += 1;
+    }
+    Err(Error::DestinationUnreachable.into())
+}
+
+/// Makes an A* search that can be stopped by [`SearchLimits`], returns the path as a
+/// `Vec<Dir>`
+///
+/// Generic interface over types that implement [`MapPos`] for [`Dir`] and `usize`
+///
+/// This is essentially [`search_mapmov_limits`] followed by a call to
+/// [`camefrom_into_path`](crate::camefrom_into_path).
+pub fn search_path_limits<
+    F,
+    MapPosDir,
+    MapPosUsize,
+    P,
+    const D: bool,
+    const WORDS: usize,
+    const SIZE: usize,
+>(
+    go: F,
+    orig: &P,
+    dest: &P,
+    limits: &SearchLimits<'_>,
+) -> Result<Vec<Dir>, LimitsError<MapPosDir>>
+where
+    F: Fn(P, Dir) -> Option<P>,
+    MapPosDir: MapPos<Option<Dir>, P, WORDS, SIZE> + Default,
+    MapPosUsize: MapPos<usize, P, WORDS, SIZE> + Default,
+    P: PosT,
+    P: core::ops::Add<Dir, Output = Result<P, Error>>,
+    P: Ord,
+    P: Copy,
+{
+    let mapmov = search_mapmov_limits::<F, MapPosDir, MapPosUsize, P, D, WORDS, SIZE>(
+        go, orig, dest, limits,
+    )?;
+    Ok(camefrom_into_path(mapmov, orig, dest)?)
+}
+
 /* Parameterized interface ****************************************************/
 
 /// Makes an A* search using [`Grid`], returns the path as a `Vec<Dir>`
+///
+/// The grids are allocated on the heap when they are larger than
+/// [`GRID_BOX_THRESHOLD`](crate::GRID_BOX_THRESHOLD).
 pub fn search_path_grid<F, P, const D: bool, const WORDS: usize, const SIZE: usize>(
     go: F,
     orig: &P,
@@ -207,17 +398,66 @@ pub fn search_path_grid<F, P, const D: bool, const WORDS: usize, const SIZE: usi
 where
     F: Fn(P, Dir) -> Option<P>,
     P: PosT,
-    P: std::ops::Add<Dir, Output = Result<P, Error>>,
+    P: core::ops::Add<Dir, Output = Result<P, Error>>,
+    P: Ord,
+    P: Copy,
+{
+    if Grid::<usize, P, SIZE>::SHOULD_BOX {
+        search_path::<
+            F,
+            Box<Grid<Option<Dir>, P, SIZE>>,
+            Box<Grid<usize, P, SIZE>>,
+            P,
+            D,
+            WORDS,
+            SIZE,
+        >(go, orig, dest)
+    } else {
+        search_path::<F, Grid<Option<Dir>, P, SIZE>, Grid<usize, P, SIZE>, P, D, WORDS, SIZE>(
+            go, orig, dest,
+        )
+    }
+}
+
+/// Makes an A* search that can be stopped by [`SearchLimits`] using [`Grid`],
+/// returns the path as a `Vec<Dir>`
+///
+/// The grids are allocated on the heap when they are larger than
+/// [`GRID_BOX_THRESHOLD`](crate::GRID_BOX_THRESHOLD).
+pub fn search_path_limits_grid<F, P, const D: bool, const WORDS: usize, const SIZE: usize>(
+    go: F,
+    orig: &P,
+    dest: &P,
+    limits: &SearchLimits<'_>,
+) -> Result<Vec<Dir>, LimitsErrorGrid<P, SIZE>>
+where
+    F: Fn(P, Dir) -> Option<P>,
+    P: PosT,
+    P: core::ops::Add<Dir, Output = Result<P, Error>>,
     P: Ord,
     P: Copy,
 {
-    search_path::<F, Grid<Option<Dir>, P, SIZE>, Grid<usize, P, SIZE>, P, D, WORDS, SIZE>(
-        go, orig, dest,
-    )
+    if Grid::<usize, P, SIZE>::SHOULD_BOX {
+        search_path_limits::<
+            F,
+            Box<Grid<Option<Dir>, P, SIZE>>,
+            Box<Grid<usize, P, SIZE>>,
+            P,
+            D,
+            WORDS,
+            SIZE,
+        >(go, orig, dest, limits)
+    } else {
+        search_path_limits::<F, Grid<Option<Dir>, P, SIZE>, Grid<usize, P, SIZE>, P, D, WORDS, SIZE>(
+            go, orig, dest, limits,
+        )
+        .map_err(|e| e.map(Box::new))
+    }
 }
 
 /// Makes an A* search using the [`HashMap`](std::collections::HashMap)] type,
 /// returns the path as a `Vec<Dir>`
+#[cfg(feature = "std")]
 pub fn search_path_hash<F, P, const D: bool, const WORDS: usize, const SIZE: usize>(
     go: F,
     orig: &P,
@@ -226,15 +466,15 @@ pub fn search_path_hash<F, P, const D: bool, const WORDS: usize, const SIZE: usi
 where
     F: Fn(P, Dir) -> Option<P>,
     P: PosT,
-    P: std::ops::Add<Dir, Output = Result<P, Error>>,
-    P: Eq + std::hash::Hash,
+    P: core::ops::Add<Dir, Output = Result<P, Error>>,
+    P: Eq + core::hash::Hash,
     P: Ord,
     P: Copy,
 {
     search_path::<
         F,
-        (collections::HashMap<P, Option<Dir>>, Option<Dir>),
-        (collections::HashMap<P, usize>, usize),
+        (std::collections::HashMap<P, Option<Dir>>, Option<Dir>),
+        (std::collections::HashMap<P, usize>, usize),
         P,
         D,
         WORDS,
@@ -242,7 +482,7 @@ where
     >(go, orig, dest)
 }
 
-/// Makes an A* search using the [`BTreeMap`](std::collections::BTreeMap) type,
+/// Makes an A* search using the [`BTreeMap`](alloc::collections::BTreeMap) type,
 /// returns the path as a `Vec<Dir>`
 pub fn search_path_btree<F, P, const D: bool, const WORDS: usize, const SIZE: usize>(
     go: F,
@@ -252,7 +492,7 @@ pub fn search_path_btree<F, P, const D: bool, const WORDS: usize, const SIZE: us
 where
     F: Fn(P, Dir) -> Option<P>,
     P: PosT,
-    P: std::ops::Add<Dir, Output = Result<P, Error>>,
+    P: core::ops::Add<Dir, Output = Result<P, Error>>,
     P: Ord,
     P: Copy,
 {
@@ -278,7 +518,7 @@ impl<const W: u16, const H: u16, const D: bool, const WORDS: usize, const SIZE:
     where
         F: Fn(P, Dir) -> Option<P>,
         P: PosT,
-        P: std::ops::Add<Dir, Output = Result<P, Error>>,
+        P: core::ops::Add<Dir, Output = Result<P, Error>>,
         P: Ord,
         P: Copy,
     {
@@ -291,7 +531,7 @@ impl<const W: u16, const H: u16, const D: bool, const WORDS: usize, const SIZE:
     where
         F: Fn(P, Dir) -> Option<P>,
         P: PosT,
-        P: std::ops::Add<Dir, Output = Result<P, Error>>,
+        P: core::ops::Add<Dir, Output = Result<P, Error>>,
         P: Ord,
         P: Copy,
     {
@@ -300,28 +540,47 @@ impl<const W: u16, const H: u16, const D: bool, const WORDS: usize, const SIZE:
 
     /// Perform an A* search using a [`HashMap`](std::collections::HashMap) internally;
     /// see [`astar`](crate::astar)
+    #[cfg(feature = "std")]
     pub fn astar_path_hash<F, P>(go: F, orig: &P, dest: &P) -> Result<Vec<Dir>, Error>
     where
         F: Fn(P, Dir) -> Option<P>,
         P: PosT,
-        P: std::ops::Add<Dir, Output = Result<P, Error>>,
-        P: Eq + std::hash::Hash,
+        P: core::ops::Add<Dir, Output = Result<P, Error>>,
+        P: Eq + core::hash::Hash,
         P: Ord,
         P: Copy,
     {
         search_path_hash::<F, P, D, WORDS, SIZE>(go, orig, dest)
     }
 
-    /// Perform an A* search using a [`BTreeMap`](std::collections::BTreeMap) internally;
+    /// Perform an A* search using a [`BTreeMap`](alloc::collections::BTreeMap) internally;
     /// see [`astar`](crate::astar)
     pub fn astar_path_btree<F, P>(go: F, orig: &P, dest: &P) -> Result<Vec<Dir>, Error>
     where
         F: Fn(P, Dir) -> Option<P>,
         P: PosT,
-        P: std::ops::Add<Dir, Output = Result<P, Error>>,
+        P: core::ops::Add<Dir, Output = Result<P, Error>>,
         P: Ord,
         P: Copy,
     {
         search_path_btree::<F, P, D, WORDS, SIZE>(go, orig, dest)
     }
+
+    /// Perform an A* search that can be stopped by [`SearchLimits`], using a
+    /// [`Grid`] internally; see [`astar`](crate::astar) and [`limits`](crate::limits)
+    pub fn astar_path_limits<F, P>(
+        go: F,
+        orig: &P,
+        dest: &P,
+        limits: &SearchLimits<'_>,
+    ) -> Result<Vec<Dir>, LimitsErrorGrid<P, SIZE>>
+    where
+        F: Fn(P, Dir) -> Option<P>,
+        P: PosT,
+        P: core::ops::Add<Dir, Output = Result<P, Error>>,
+        P: Ord,
+        P: Copy,
+    {
+        search_path_limits_grid::<F, P, D, WORDS, SIZE>(go, orig, dest, limits)
+    }
 }
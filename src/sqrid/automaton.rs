@@ -0,0 +1,326 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+//! Cellular automata
+//!
+//! This module adds cellular automata support to [`Grid`] and [`Gridbool`]:
+//! - [`Grid::step_automaton`] updates all positions of a grid at once, using a rule that
+//!   gets the current value of each position and the values of its neighbors;
+//!   [`Grid::step_automaton_into`] writes the result to a second grid instead, so that
+//!   callers can alternate between two buffers;
+//! - [`Gridbool::step_life`] is a bit-parallel implementation of Conway's Game of Life,
+//!   that processes 64 positions at a time using word operations.
+//!
+//! Both can treat the grid as bounded, where the positions outside are missing, or as
+//! toroidal, where the edges wrap around - see [`Edges`]. They also have a `run_*` variant
+//! that keeps stepping until a fixed point is reached.
+//!
+//! Example usage:
+//!
+//! ```
+//! use sqrid::automaton::Edges;
+//!
+//! type Sqrid = sqrid::sqrid_create!(4, 4, false);
+//! type Pos = sqrid::pos_create!(Sqrid);
+//! type Grid = sqrid::grid_create!(Sqrid, bool);
+//!
+//! // Blinker:
+//! let mut grid = Grid::default();
+//! for x in 1..4 {
+//!     grid[Pos::new(x, 2)?] = true;
+//! }
+//! let life = |alive: &bool, neighbors: &[Option<&bool>]| {
+//!     let count = neighbors.iter().filter(|&&n| n == Some(&true)).count();
+//!     count == 3 || (*alive && count == 2)
+//! };
+//! grid.step_automaton::<true>(Edges::Bounded, life);
+//! assert!(grid[Pos::new(2, 1)?] && grid[Pos::new(2, 3)?]);
+//! assert!(!grid[Pos::new(1, 2)?]);
+//! # Ok::<(), sqrid::Error>(())
+//! ```
+
+use alloc::vec;
+
+use super::postrait::PosT;
+use super::Dir;
+use super::Grid;
+use super::Gridbool;
+
+/// How the edges of the grid are handled by the automata
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Edges {
+    /// The positions outside the grid are missing
+    #[default]
+    Bounded,
+    /// The edges wrap around: the left neighbor of the first column is in the last column,
+    /// the top neighbor of the first line is in the last line, etc.
+    Toroidal,
+}
+
+impl Edges {
+    /// Return the coordinate `c + delta` in a dimension of size `size`, if valid
+    #[inline]
+    fn add(&self, c: usize, delta: isize, size: usize) -> Option<usize> {
+        match self {
+            Edges::Bounded => c.checked_add_signed(delta).filter(|&n| n < size),
+            Edges::Toroidal => Some((c as isize + delta).rem_euclid(size as isize) as usize),
+        }
+    }
+}
+
+/* Grid ***********************************************************************/
+
+impl<T, P: PosT, const SIZE: usize> Grid<T, P, SIZE> {
+    /// Write the next state of the grid to `next`, using the provided rule
+    ///
+    /// The rule gets the current value of each position and the
+    /// values of its neighbors, in the order of [`Dir::iter::<D>()`](Dir::iter);
+    /// neighbors outside of a bounded grid are `None`. The grid
+    /// itself is not changed, which allows us to alternate between
+    /// two grids without allocating a new one in each step.
+    ///
+    /// Returns `true` if any value of `next` is different from the
+    /// corresponding value of the grid.
+    pub fn step_automaton_into<const D: bool>(
+        &self,
+        next: &mut Self,
+        edges: Edges,
+        mut rule: impl FnMut(&T, &[Option<&T>]) -> T,
+    ) -> bool
+    where
+        T: PartialEq,
+    {
+        let width = P::width();
+        let height = P::height();
+        let cells = self.as_array();
+        let mut changed = false;
+        for (i, out) in next.iter_mut().enumerate() {
+            let (x, y) = (i % width, i / width);
+            let mut neighbors = [None; 8];
+            for (neighbor, dir) in neighbors.iter_mut().zip(Dir::iter::<D>()) {
+                let (dx, dy) = <(isize, isize)>::from(dir);
+                *neighbor = edges
+                    .add(x, dx, width)
+                    .zip(edges.add(y, dy, height))
+                    .map(|(nx, ny)| &cells[ny * width + nx]);
+            }
+            let count = if D { 8 } else { 4 };
+            let value = rule(&cells[i], &neighbors[..count]);
+            changed |= value != cells[i];
+            *out = value;
+        }
+        changed
+    }
+
+    /// Update all positions of the grid using the provided rule
+    ///
+    /// All positions are updated at once, using the values from
+    /// before the step; see [`Grid::step_automaton_into`].
+    ///
+    /// Returns `true` if any value changed.
+    pub fn step_automaton<const D: bool>(
+        &mut self,
+        edges: Edges,
+        rule: impl FnMut(&T, &[Option<&T>]) -> T,
+    ) -> bool
+    where
+        T: Clone + PartialEq,
+    {
+        let mut next = self.clone();
+        let changed = self.step_automaton_into::<D>(&mut next, edges, rule);
+        *self = next;
+        changed
+    }
+
+    /// Call [`Grid::step_automaton`] until a fixed point is reached
+    ///
+    /// Runs at most `max_steps` steps, alternating between the grid
+    /// and a single second buffer. Returns the number of steps that
+    /// changed the grid, or `None` if no fixed point was found.
+    pub fn run_automaton<const D: bool>(
+        &mut self,
+        edges: Edges,
+        max_steps: usize,
+        mut rule: impl FnMut(&T, &[Option<&T>]) -> T,
+    ) -> Option<usize>
+    where
+        T: Clone + PartialEq,
+    {
+        let mut next = self.clone();
+        for step in 0..max_steps {
+            let changed = self.step_automaton_into::<D>(&mut next, edges, &mut rule);
+            core::mem::swap(self, &mut next);
+            if !changed {
+                return Some(step);
+            }
+        }
+        None
+    }
+}
+
+/* Gridbool *******************************************************************/
+
+/// Read `len` bits, up to 64, starting at bit `start` of a MSB-first
+/// bitmap; the bits are returned in the most significant bits.
+#[inline]
+fn read_bits(words: &[u32], start: usize, len: usize) -> u64 {
+    let first = start / 32;
+    let mut window = 0_u128;
+    for i in 0..3 {
+        let word = words.get(first + i).copied().unwrap_or(0);
+        window |= (word as u128) << (96 - 32 * i);
+    }
+    let bits = ((window << (start % 32)) >> 64) as u64;
+    bits & !(u64::MAX.checked_shr(len as u32).unwrap_or(0))
+}
+
+/// Sequential writer of a MSB-first bitmap
+struct BitWriter<'a> {
+    words: &'a mut [u32],
+    acc: u64,
+    nbits: usize,
+    index: usize,
+}
+
+impl<'a> BitWriter<'a> {
+    fn new(words: &'a mut [u32]) -> Self {
+        BitWriter {
+            words,
+            acc: 0,
+            nbits: 0,
+            index: 0,
+        }
+    }
+
+    /// Write the `len` most significant bits of `bits`, up to 32
+    fn write(&mut self, bits: u64, len: usize) {
+        self.acc |= (bits & !(u64::MAX >> len)) >> self.nbits;
+        self.nbits += len;
+        if self.nbits >= 32 {
+            self.words[self.index] = (self.acc >> 32) as u32;
+            self.index += 1;
+            self.acc <<= 32;
+            self.nbits -= 32;
+        }
+    }
+
+    fn finish(self) {
+        if self.nbits > 0 {
+            self.words[self.index] = (self.acc >> 32) as u32;
+        }
+    }
+}
+
+impl<P: PosT, const WORDS: usize> Gridbool<P, WORDS> {
+    /// Read line `y` into `out`, with the bits of the line starting
+    /// at a word boundary; `None` is an empty line
+    fn read_line(&self, y: Option<usize>, out: &mut [u64]) {
+        let width = P::width();
+        for (w, word) in out.iter_mut().enumerate() {
+            let x = 64 * w;
+            *word = y.map_or(0, |y| {
+                read_bits(self.as_inner(), y * width + x, (width - x).min(64))
+            });
+        }
+    }
+
+    /// Perform a step of Conway's Game of Life
+    ///
+    /// The cells with exactly 3 live neighbors become alive, the
+    /// live cells with 2 or 3 live neighbors survive, and all other
+    /// cells die. This is implemented with word operations that
+    /// process 64 cells at a time.
+    ///
+    /// Returns `true` if any cell changed.
+    pub fn step_life(&mut self, edges: Edges) -> bool {
+        let width = P::width();
+        let height = P::height();
+        let nwords = width.div_ceil(64);
+        let lastbit = |line: &[u64], x: usize| (line[x / 64] << (x % 64)) & (1 << 63);
+        // Neighbors in the west have their bits shifted right, and
+        // vice-versa:
+        let shift_r = |line: &[u64], w: usize| {
+            let mut word = line[w] >> 1 | if w > 0 { line[w - 1] << 63 } else { 0 };
+            if edges == Edges::Toroidal && w == 0 {
+                word |= lastbit(line, width - 1);
+            }
+            word
+        };
+        let shift_l = |line: &[u64], w: usize| {
+            let mut word = line[w] << 1 | line.get(w + 1).map_or(0, |n| n >> 63);
+            let x = width - 1;
+            if edges == Edges::Toroidal && w == x / 64 {
+                word |= lastbit(line, 0) >> (x % 64);
+            }
+            word
+        };
+        // Line buffers with the lines above, at and below the
+        // current one, rotated as we go down:
+        let mut lines = [
+            vec![0_u64; nwords],
+            vec![0_u64; nwords],
+            vec![0_u64; nwords],
+        ];
+        let mut inner = [0_u32; WORDS];
+        let mut writer = BitWriter::new(&mut inner);
+        for y in 0..height {
+            if y == 0 {
+                self.read_line(edges.add(y, -1, height), &mut lines[0]);
+                self.read_line(Some(y), &mut lines[1]);
+            } else {
+                lines.rotate_left(1);
+            }
+            self.read_line(edges.add(y, 1, height), &mut lines[2]);
+            let [above, line, below] = &lines;
+            for w in 0..nwords {
+                let neighbors = [
+                    above[w],
+                    shift_r(above, w),
+                    shift_l(above, w),
+                    shift_r(line, w),
+                    shift_l(line, w),
+                    below[w],
+                    shift_r(below, w),
+                    shift_l(below, w),
+                ];
+                // Bit-sliced counter: s0 and s1 are the count modulo
+                // 4, s2 flags counts of 4 or more:
+                let (mut s0, mut s1, mut s2) = (0_u64, 0_u64, 0_u64);
+                for n in neighbors {
+                    let c0 = s0 & n;
+                    s0 ^= n;
+                    let c1 = s1 & c0;
+                    s1 ^= c0;
+                    s2 |= c1;
+                }
+                let alive = !s2 & s1 & (s0 | line[w]);
+                let len = (width - 64 * w).min(64);
+                writer.write(alive, len.min(32));
+                if len > 32 {
+                    writer.write(alive << 32, len - 32);
+                }
+            }
+        }
+        writer.finish();
+        // Keep the padding bits, as Gridbool::ALL_TRUE sets them:
+        let valid = width * height % 32;
+        if valid > 0 {
+            inner[WORDS - 1] |= self.as_inner()[WORDS - 1] & (u32::MAX >> valid);
+        }
+        let changed = inner != *self.as_inner();
+        *self.as_inner_mut() = inner;
+        changed
+    }
+
+    /// Call [`Gridbool::step_life`] until a fixed point is reached
+    ///
+    /// Runs at most `max_steps` steps. Returns the number of steps
+    /// that changed the grid, or `None` if no fixed point was found.
+    pub fn run_life(&mut self, edges: Edges, max_steps: usize) -> Option<usize> {
+        (0..max_steps).find(|_| !self.step_life(edges))
+    }
+}
@@ -19,7 +19,7 @@
 //! convenient set of functions plugged into [`Sqrid`] that has no such
 //! requirement:
 //! - [`Sqrid::bf_iter_grid`]
-//! - [`Sqrid::bf_iter_hash`]
+#![cfg_attr(feature = "std", doc = " - [`Sqrid::bf_iter_hash`]")]
 //! - [`Sqrid::bf_iter_btree`]
 //! - [`Sqrid::bf_iter`]: alias for `bf_iter_grid`.
 //!
@@ -56,7 +56,7 @@
 //! generic parameters explicitly, and a more convenient set of
 //! functions plugged into the [`Sqrid`] type:
 //! - [`Sqrid::bfs_path_grid`]
-//! - [`Sqrid::bfs_path_hash`]
+#![cfg_attr(feature = "std", doc = " - [`Sqrid::bfs_path_hash`]")]
 //! - [`Sqrid::bfs_path_btree`]
 //! - [`Sqrid::bfs_path`]: alias for `bf_path_grid`.
 //!
@@ -75,28 +75,50 @@
 //! }
 //! ```
 
-use std::collections;
-use std::mem;
+use alloc::boxed::Box;
+use alloc::collections;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::mem;
 
 use super::camefrom_into_path;
+use super::stats::Timer;
 use super::Dir;
 use super::Error;
 use super::Grid;
 use super::Gridbool;
+use super::LimitsError;
+use super::LimitsErrorGrid;
 use super::MapPos;
 use super::PosT;
+use super::SearchLimits;
+use super::SearchObserver;
+use super::SearchStats;
 use super::SetPos;
 use super::Sqrid;
 
 /* BfIterator *****************************************************************/
 
 /// Breadth-first iterator
+///
+/// Can optionally collect [`SearchStats`] and call a [`SearchObserver`] on each expanded
+/// position; see [`stats`](crate::stats).
 #[derive(Debug, Clone)]
-pub struct BfIterator<GoFn, MySetPos, P: PosT, const D: bool, const WORDS: usize, const SIZE: usize>
-{
+pub struct BfIterator<
+    GoFn,
+    MySetPos,
+    P: PosT,
+    const D: bool,
+    const WORDS: usize,
+    const SIZE: usize,
+    Obs = (),
+> {
     visited: MySetPos,
     nextfront: Vec<(P, Dir)>,
     go: GoFn,
+    distance: usize,
+    stats: Option<SearchStats>,
+    observer: Obs,
 }
 
 impl<GoFn, MySetPos, P: PosT, const D: bool, const WORDS: usize, const SIZE: usize>
@@ -110,43 +132,120 @@ where
     where
         GoFn: Fn(P, Dir) -> Option<P>,
     {
-        let mut bfs = BfIterator {
+        BfIterator {
             visited: MySetPos::default(),
             nextfront: vec![(*orig, Dir::default())],
             go,
-        };
-        // Process origins:
-        let _ = bfs.next();
-        bfs
+            distance: 0,
+            stats: None,
+            observer: (),
+        }
     }
 }
 
-impl<GoFn, MySetPos, P: PosT, const D: bool, const WORDS: usize, const SIZE: usize> Iterator
-    for BfIterator<GoFn, MySetPos, P, D, WORDS, SIZE>
+impl<GoFn, MySetPos, P: PosT, const D: bool, const WORDS: usize, const SIZE: usize, Obs>
+    BfIterator<GoFn, MySetPos, P, D, WORDS, SIZE, Obs>
+{
+    /// Enable the collection of [`SearchStats`]
+    pub fn with_stats(mut self) -> Self {
+        self.stats = Some(SearchStats::default());
+        self
+    }
+
+    /// Return the [`SearchStats`] collected so far, if enabled by
+    /// [`BfIterator::with_stats`]
+    pub fn stats(&self) -> Option<&SearchStats> {
+        self.stats.as_ref()
+    }
+
+    /// Set the [`SearchObserver`] called with each position and its
+    /// distance from the origin when they are expanded
+    pub fn with_observer<O>(self, observer: O) -> BfIterator<GoFn, MySetPos, P, D, WORDS, SIZE, O>
+    where
+        O: SearchObserver<P>,
+    {
+        BfIterator {
+            visited: self.visited,
+            nextfront: self.nextfront,
+            go: self.go,
+            distance: self.distance,
+            stats: self.stats,
+            observer,
+        }
+    }
+}
+
+impl<GoFn, MySetPos, P: PosT, const D: bool, const WORDS: usize, const SIZE: usize, Obs>
+    BfIterator<GoFn, MySetPos, P, D, WORDS, SIZE, Obs>
 where
     GoFn: Fn(P, Dir) -> Option<P>,
     MySetPos: SetPos<P, WORDS, SIZE>,
+    Obs: SearchObserver<P>,
     P: Copy,
 {
-    type Item = Vec<(P, Dir)>;
-    fn next(&mut self) -> Option<Self::Item> {
-        let front = mem::take(&mut self.nextfront);
-        if front.is_empty() {
-            return None;
-        }
+    /// Expand the current front, generating the next one
+    fn step(&mut self) -> Option<Vec<(P, Dir)>> {
+        let front = self.take_front()?;
         for &(pos, _) in &front {
-            for dir in Dir::iter::<D>() {
-                if let Some(next_pos) = (self.go)(pos, dir) {
-                    if self.visited.contains(&next_pos) {
-                        continue;
-                    }
-                    self.nextfront.push((next_pos, -dir));
-                    self.visited.insert(next_pos);
+            self.expand(pos);
+        }
+        self.finish_front(front.len());
+        Some(front)
+    }
+
+    /// Take the current front, without expanding it
+    fn take_front(&mut self) -> Option<Vec<(P, Dir)>> {
+        let front = mem::take(&mut self.nextfront);
+        (!front.is_empty()).then_some(front)
+    }
+
+    /// Expand a position of the current front, adding its neighbors
+    /// to the next one
+    fn expand(&mut self, pos: P) {
+        self.observer.on_expand(pos, self.distance);
+        for dir in Dir::iter::<D>() {
+            if let Some(next_pos) = (self.go)(pos, dir) {
+                if self.visited.contains(&next_pos) {
+                    continue;
                 }
+                self.nextfront.push((next_pos, -dir));
+                self.visited.insert(next_pos);
             }
-            self.visited.insert(pos);
         }
-        Some(front)
+        self.visited.insert(pos);
+    }
+
+    /// Finish the expansion of the current front, which has `len`
+    /// positions
+    fn finish_front(&mut self, len: usize) {
+        if let Some(stats) = &mut self.stats {
+            stats.expanded += len;
+            stats.max_frontier = stats.max_frontier.max(self.nextfront.len());
+        }
+        self.distance += 1;
+    }
+}
+
+impl<GoFn, MySetPos, P: PosT, const D: bool, const WORDS: usize, const SIZE: usize, Obs> Iterator
+    for BfIterator<GoFn, MySetPos, P, D, WORDS, SIZE, Obs>
+where
+    GoFn: Fn(P, Dir) -> Option<P>,
+    MySetPos: SetPos<P, WORDS, SIZE>,
+    Obs: SearchObserver<P>,
+    P: Copy,
+{
+    type Item = Vec<(P, Dir)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = self.stats.is_some().then(Timer::start);
+        if self.distance == 0 {
+            // Process origins:
+            let _ = self.step();
+        }
+        let front = self.step();
+        if let (Some(stats), Some(start)) = (&mut self.stats, start) {
+            stats.elapsed += start.elapsed();
+        }
+        front
     }
 }
 
@@ -193,7 +292,7 @@ where
     P: PosT,
     P: Copy,
 {
-    let mut from = MapPosDir::default();
+    let mut from = MapPosDir::new(None);
     for (pos, dir) in bf_iter::<GoFn, MySetPos, P, D, WORDS, SIZE>(go, orig).flatten() {
         from.set(pos, Some(dir));
         if found(pos) {
@@ -231,13 +330,112 @@ where
     P: PosT,
     P: PartialEq,
     P: Copy,
-    P: std::ops::Add<Dir, Output = Result<P, Error>>,
+    P: core::ops::Add<Dir, Output = Result<P, Error>>,
 {
     let (dest, mapmov) =
         search_mapmov::<GoFn, FoundFn, MapPosDir, MySetPos, P, D, WORDS, SIZE>(go, orig, found)?;
     Ok((dest, camefrom_into_path(mapmov, orig, &dest)?))
 }
 
+/// Make a breadth-first search that can be stopped by [`SearchLimits`], return the
+/// "came from" direction [`MapPos`]
+///
+/// The cost limit is the distance from the origin, and the fronts are expanded only if
+/// all their positions fit in the maximum number of expanded positions. Returns
+/// [`LimitsError::BudgetExceeded`] with the positions visited so far when a limit is
+/// reached before the destination.
+///
+/// Generic interface over types that implement [`MapPos`] for [`Dir`] and `usize`
+pub fn search_mapmov_limits<
+    GoFn,
+    FoundFn,
+    MapPosDir,
+    MySetPos,
+    P,
+    const D: bool,
+    const WORDS: usize,
+    const SIZE: usize,
+>(
+    go: GoFn,
+    orig: &P,
+    found: FoundFn,
+    limits: &SearchLimits<'_>,
+) -> Result<(P, MapPosDir), LimitsError<MapPosDir>>
+where
+    GoFn: Fn(P, Dir) -> Option<P>,
+    FoundFn: Fn(P) -> bool,
+    MapPosDir: MapPos<Option<Dir>, P, WORDS, SIZE> + Default,
+    MySetPos: SetPos<P, WORDS, SIZE> + Default,
+    P: PosT,
+    P: Copy,
+{
+    let mut from = MapPosDir::new(None);
+    let mut it = bf_iter::<GoFn, MySetPos, P, D, WORDS, SIZE>(go, orig);
+    let mut expanded = 0;
+    while let Some(front) = it.take_front() {
+        if limits.cost_exceeded(it.distance) {
+            return Err(LimitsError::BudgetExceeded(from));
+        }
+        // The origin is expanded but not checked, as in the iterator:
+        if it.distance > 0 {
+            for &(pos, dir) in &front {
+                from.set(pos, Some(dir));
+                if found(pos) {
+                    return Ok((pos, from));
+                }
+            }
+        }
+        if limits.expansion_exceeded(expanded, front.len()) {
+            return Err(LimitsError::BudgetExceeded(from));
+        }
+        for &(pos, _) in &front {
+            it.expand(pos);
+        }
+        it.finish_front(front.len());
+        expanded += front.len();
+    }
+    Err(Error::DestinationUnreachable.into())
+}
+
+/// Makes a breadth-first search that can be stopped by [`SearchLimits`], returns the path
+/// as a `Vec<Dir>`
+///
+/// Generic interface over types that implement [`MapPos`] for [`Dir`] and `usize`
+///
+/// This is essentially [`search_mapmov_limits`] followed by a call to
+/// [`camefrom_into_path`](crate::camefrom_into_path).
+pub fn search_path_limits<
+    GoFn,
+    FoundFn,
+    MapPosDir,
+    MySetPos,
+    P,
+    const D: bool,
+    const WORDS: usize,
+    const SIZE: usize,
+>(
+    go: GoFn,
+    orig: &P,
+    found: FoundFn,
+    limits: &SearchLimits<'_>,
+) -> Result<(P, Vec<Dir>), LimitsError<MapPosDir>>
+where
+    GoFn: Fn(P, Dir) -> Option<P>,
+    FoundFn: Fn(P) -> bool,
+    MapPosDir: MapPos<Option<Dir>, P, WORDS, SIZE> + Default,
+    MySetPos: SetPos<P, WORDS, SIZE> + Default,
+    P: PosT,
+    P: PartialEq,
+    P: Copy,
+    P: core::ops::Add<Dir, Output = Result<P, Error>>,
+{
+    let (dest, mapmov) =
+        search_mapmov_limits::<GoFn, FoundFn, MapPosDir, MySetPos, P, D, WORDS, SIZE>(
+            go, orig, found, limits,
+        )?;
+    Ok((dest, camefrom_into_path(mapmov, orig, &dest)?))
+}
+
 /* Parameterized interface ****************************************************/
 
 /* bf_iter parameterized: */
@@ -257,21 +455,22 @@ where
 
 /// Create new breadth-first iterator using the
 /// [`HashSet`](std::collections::HashSet)] type internally
+#[cfg(feature = "std")]
 pub fn bf_iter_hash<GoFn, P, const D: bool, const WORDS: usize, const SIZE: usize>(
     go: GoFn,
     orig: &P,
-) -> BfIterator<GoFn, collections::HashSet<P>, P, D, WORDS, SIZE>
+) -> BfIterator<GoFn, std::collections::HashSet<P>, P, D, WORDS, SIZE>
 where
     GoFn: Fn(P, Dir) -> Option<P>,
     P: PosT,
-    P: Eq + std::hash::Hash,
+    P: Eq + core::hash::Hash,
     P: Copy,
 {
-    bf_iter::<GoFn, collections::HashSet<P>, P, D, WORDS, SIZE>(go, orig)
+    bf_iter::<GoFn, std::collections::HashSet<P>, P, D, WORDS, SIZE>(go, orig)
 }
 
 /// Create new breadth-first iterator using the
-/// [`BTreeSet`](std::collections::BTreeSet) type internally
+/// [`BTreeSet`](alloc::collections::BTreeSet) type internally
 pub fn bf_iter_btree<GoFn, P, const D: bool, const WORDS: usize, const SIZE: usize>(
     go: GoFn,
     orig: &P,
@@ -288,6 +487,9 @@ where
 /* search_path parameterized: */
 
 /// Makes an BF search using [`Grid`], returns the path as a `Vec<Dir>`
+///
+/// The grids are allocated on the heap when they are larger than
+/// [`GRID_BOX_THRESHOLD`](crate::GRID_BOX_THRESHOLD).
 pub fn search_path_grid<GoFn, FoundFn, P, const D: bool, const WORDS: usize, const SIZE: usize>(
     go: GoFn,
     orig: &P,
@@ -298,17 +500,90 @@ where
     FoundFn: Fn(P) -> bool,
     P: PosT,
     P: PartialEq,
-    P: std::ops::Add<Dir, Output = Result<P, Error>>,
+    P: core::ops::Add<Dir, Output = Result<P, Error>>,
+    P: Copy,
+{
+    if Grid::<Option<Dir>, P, SIZE>::SHOULD_BOX {
+        search_path::<
+            GoFn,
+            FoundFn,
+            Box<Grid<Option<Dir>, P, SIZE>>,
+            Box<Gridbool<P, WORDS>>,
+            P,
+            D,
+            WORDS,
+            SIZE,
+        >(go, orig, found)
+    } else {
+        search_path::<
+            GoFn,
+            FoundFn,
+            Grid<Option<Dir>, P, SIZE>,
+            Gridbool<P, WORDS>,
+            P,
+            D,
+            WORDS,
+            SIZE,
+        >(go, orig, found)
+    }
+}
+
+/// Makes an BF search that can be stopped by [`SearchLimits`] using [`Grid`],
+/// returns the path as a `Vec<Dir>`
+///
+/// The grids are allocated on the heap when they are larger than
+/// [`GRID_BOX_THRESHOLD`](crate::GRID_BOX_THRESHOLD).
+pub fn search_path_limits_grid<
+    GoFn,
+    FoundFn,
+    P,
+    const D: bool,
+    const WORDS: usize,
+    const SIZE: usize,
+>(
+    go: GoFn,
+    orig: &P,
+    found: FoundFn,
+    limits: &SearchLimits<'_>,
+) -> Result<(P, Vec<Dir>), LimitsErrorGrid<P, SIZE>>
+where
+    GoFn: Fn(P, Dir) -> Option<P>,
+    FoundFn: Fn(P) -> bool,
+    P: PosT,
+    P: PartialEq,
+    P: core::ops::Add<Dir, Output = Result<P, Error>>,
     P: Copy,
 {
-    search_path::<GoFn, FoundFn, Grid<Option<Dir>, P, SIZE>, Gridbool<P, WORDS>, P, D, WORDS, SIZE>(
-        go, orig, found,
-    )
+    if Grid::<Option<Dir>, P, SIZE>::SHOULD_BOX {
+        search_path_limits::<
+            GoFn,
+            FoundFn,
+            Box<Grid<Option<Dir>, P, SIZE>>,
+            Box<Gridbool<P, WORDS>>,
+            P,
+            D,
+            WORDS,
+            SIZE,
+        >(go, orig, found, limits)
+    } else {
+        search_path_limits::<
+            GoFn,
+            FoundFn,
+            Grid<Option<Dir>, P, SIZE>,
+            Gridbool<P, WORDS>,
+            P,
+            D,
+            WORDS,
+            SIZE,
+        >(go, orig, found, limits)
+        .map_err(|e| e.map(Box::new))
+    }
 }
 
 /// Makes an BF search using the
 /// [`HashMap`](std::collections::HashMap)/[`HashSet`](std::collections::HashSet)
 /// types; returns the path as a `Vec<Dir>`
+#[cfg(feature = "std")]
 pub fn search_path_hash<GoFn, FoundFn, P, const D: bool, const WORDS: usize, const SIZE: usize>(
     go: GoFn,
     orig: &P,
@@ -318,15 +593,15 @@ where
     GoFn: Fn(P, Dir) -> Option<P>,
     FoundFn: Fn(P) -> bool,
     P: PosT,
-    P: std::ops::Add<Dir, Output = Result<P, Error>>,
-    P: Eq + std::hash::Hash,
+    P: core::ops::Add<Dir, Output = Result<P, Error>>,
+    P: Eq + core::hash::Hash,
     P: Copy,
 {
     search_path::<
         GoFn,
         FoundFn,
-        (collections::HashMap<P, Option<Dir>>, Option<Dir>),
-        collections::HashSet<P>,
+        (std::collections::HashMap<P, Option<Dir>>, Option<Dir>),
+        std::collections::HashSet<P>,
         P,
         D,
         WORDS,
@@ -335,7 +610,7 @@ where
 }
 
 /// Makes an BF search using the
-/// [`BTreeMap`](std::collections::BTreeMap)/[`BTreeSet`](std::collections::BTreeSet)
+/// [`BTreeMap`](alloc::collections::BTreeMap)/[`BTreeSet`](alloc::collections::BTreeSet)
 /// type; returns the path as a `Vec<Dir>`
 pub fn search_path_btree<GoFn, FoundFn, P, const D: bool, const WORDS: usize, const SIZE: usize>(
     go: GoFn,
@@ -346,7 +621,7 @@ where
     GoFn: Fn(P, Dir) -> Option<P>,
     FoundFn: Fn(P) -> bool,
     P: PosT,
-    P: std::ops::Add<Dir, Output = Result<P, Error>>,
+    P: core::ops::Add<Dir, Output = Result<P, Error>>,
     P: Ord,
     P: Copy,
 {
@@ -400,21 +675,22 @@ impl<const W: u16, const H: u16, const D: bool, const WORDS: usize, const SIZE:
     /// Create new breadth-first iterator using the
     /// [`HashMap`](std::collections::HashMap)]/[`HashSet`](std::collections::HashSet)]
     /// types internally; see [`bf`](crate::bf)
+    #[cfg(feature = "std")]
     pub fn bf_iter_hash<P, GoFn>(
         go: GoFn,
         orig: &P,
-    ) -> BfIterator<GoFn, collections::HashSet<P>, P, D, WORDS, SIZE>
+    ) -> BfIterator<GoFn, std::collections::HashSet<P>, P, D, WORDS, SIZE>
     where
         GoFn: Fn(P, Dir) -> Option<P>,
         P: PosT,
-        P: Eq + std::hash::Hash,
+        P: Eq + core::hash::Hash,
         P: Copy,
     {
         bf_iter_hash::<GoFn, P, D, WORDS, SIZE>(go, orig)
     }
 
     /// Create new breadth-first iterator using the
-    /// [`BTreeMap`](std::collections::BTreeMap)/[`BTreeSet`](std::collections::BTreeSet)
+    /// [`BTreeMap`](alloc::collections::BTreeMap)/[`BTreeSet`](alloc::collections::BTreeSet)
     /// types internally; see [`bf`](crate::bf)
     pub fn bf_iter_btree<P, GoFn>(
         go: GoFn,
@@ -447,7 +723,7 @@ impl<const W: u16, const H: u16, const D: bool, const WORDS: usize, const SIZE:
         FoundFn: Fn(P) -> bool,
         P: PosT,
         P: PartialEq,
-        P: std::ops::Add<Dir, Output = Result<P, Error>>,
+        P: core::ops::Add<Dir, Output = Result<P, Error>>,
         P: Copy,
     {
         Self::bfs_path_grid::<P, GoFn, FoundFn>(go, orig, found)
@@ -465,7 +741,7 @@ impl<const W: u16, const H: u16, const D: bool, const WORDS: usize, const SIZE:
         FoundFn: Fn(P) -> bool,
         P: PosT,
         P: PartialEq,
-        P: std::ops::Add<Dir, Output = Result<P, Error>>,
+        P: core::ops::Add<Dir, Output = Result<P, Error>>,
         P: Copy,
     {
         search_path_grid::<GoFn, FoundFn, P, D, WORDS, SIZE>(go, orig, found)
@@ -474,6 +750,7 @@ impl<const W: u16, const H: u16, const D: bool, const WORDS: usize, const SIZE:
     /// Perform a breadth-first search using the
     /// [`HashMap`](std::collections::HashMap)/[`HashSet`](std::collections::HashSet)
     /// types internally; see [`bf`](crate::bf)
+    #[cfg(feature = "std")]
     pub fn bfs_path_hash<P, GoFn, FoundFn>(
         go: GoFn,
         orig: &P,
@@ -483,15 +760,15 @@ impl<const W: u16, const H: u16, const D: bool, const WORDS: usize, const SIZE:
         GoFn: Fn(P, Dir) -> Option<P>,
         FoundFn: Fn(P) -> bool,
         P: PosT,
-        P: std::ops::Add<Dir, Output = Result<P, Error>>,
-        P: Eq + std::hash::Hash,
+        P: core::ops::Add<Dir, Output = Result<P, Error>>,
+        P: Eq + core::hash::Hash,
         P: Copy,
     {
         search_path_hash::<GoFn, FoundFn, P, D, WORDS, SIZE>(go, orig, found)
     }
 
     /// Perform a breadth-first search using the
-    /// [`HashMap`](std::collections::HashMap)/[`HashSet`](std::collections::HashSet)
+    /// [`BTreeMap`](alloc::collections::BTreeMap)/[`BTreeSet`](alloc::collections::BTreeSet)
     /// types internally; see [`bf`](crate::bf)
     pub fn bfs_path_btree<P, GoFn, FoundFn>(
         go: GoFn,
@@ -502,10 +779,29 @@ impl<const W: u16, const H: u16, const D: bool, const WORDS: usize, const SIZE:
         GoFn: Fn(P, Dir) -> Option<P>,
         FoundFn: Fn(P) -> bool,
         P: PosT,
-        P: std::ops::Add<Dir, Output = Result<P, Error>>,
+        P: core::ops::Add<Dir, Output = Result<P, Error>>,
         P: Ord,
         P: Copy,
     {
         search_path_btree::<GoFn, FoundFn, P, D, WORDS, SIZE>(go, orig, found)
     }
+
+    /// Perform a breadth-first search that can be stopped by [`SearchLimits`],
+    /// using a [`Grid`] internally; see [`bf`](crate::bf) and [`limits`](crate::limits)
+    pub fn bfs_path_limits<P, GoFn, FoundFn>(
+        go: GoFn,
+        orig: &P,
+        found: FoundFn,
+        limits: &SearchLimits<'_>,
+    ) -> Result<(P, Vec<Dir>), LimitsErrorGrid<P, SIZE>>
+    where
+        GoFn: Fn(P, Dir) -> Option<P>,
+        FoundFn: Fn(P) -> bool,
+        P: PosT,
+        P: PartialEq,
+        P: core::ops::Add<Dir, Output = Result<P, Error>>,
+        P: Copy,
+    {
+        search_path_limits_grid::<GoFn, FoundFn, P, D, WORDS, SIZE>(go, orig, found, limits)
+    }
 }
@@ -0,0 +1,354 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+//! 0-1 breadth-first search algorithm module
+//!
+//! This algorithm takes a movement-cost function, an origin and a destination, and figures out
+//! the path with the lowest cost by using
+//! [0-1 BFS](https://cp-algorithms.com/graph/01_bfs.html), a variation of
+//! breadth-first search that uses a double-ended queue instead of a priority queue.
+//! 0-1 BFS should be used instead of [`ucs`](crate::ucs) when the cost of each step is either
+//! `0` or `1`, as it's faster than a heap-based search.
+//! The search panics if the movement function returns a step cost greater than `1`, as the
+//! result would otherwise be silently wrong.
+//!
+//! Check out [`dial`](crate::dial) if the costs are small integers other than `0` and `1`.
+//!
+//! The base of this module is the [`Bfs01Iterator`], which yields [`super::pos::Pos`]
+//! coordinates in cost order. That iterator is used by [`search_mapmov`] to build an unsorted
+//! `super::pos::Pos`-indexed map of [`Dir`] directions, which can then transformed into a
+//! vector of directions by [`crate::camefrom_into_path`]. The complete search process is
+//! wrapped by [`search_path`].
+//!
+//! All these functions can be called directly, but that's a bit inconvenient, as they require
+//! several generic parameters. An easier alternative is provided by the wrappers plugged into
+//! the [`Sqrid`] type:
+//! - [`Sqrid::bfs01_path_grid`]
+#![cfg_attr(feature = "std", doc = " - [`Sqrid::bfs01_path_hash`]")]
+//! - [`Sqrid::bfs01_path_btree`]
+//! - [`Sqrid::bfs01_path`]: alias for `bfs01_path_grid`.
+//!
+//! Example of recommended usage:
+//!
+//! ```
+//! type Sqrid = sqrid::sqrid_create!(3, 3, false);
+//! type Pos = sqrid::pos_create!(Sqrid);
+//!
+//! fn traverse(position: Pos, direction: sqrid::Dir) -> Option<(Pos, usize)> {
+//!     let next_position = (position + direction).ok()?;
+//!     // Moving horizontally is free:
+//!     let cost = if direction == sqrid::Dir::E || direction == sqrid::Dir::W { 0 } else { 1 };
+//!     Some((next_position, cost))
+//! }
+//!
+//! if let Ok(path) = Sqrid::bfs01_path(traverse, &Pos::TOP_LEFT,
+//!                                     &Pos::BOTTOM_RIGHT) {
+//!     println!("path: {:?}", path);
+//! }
+//! ```
+
+use alloc::boxed::Box;
+use alloc::collections;
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+
+use super::camefrom_into_path;
+use super::postrait::PosT;
+use super::ucs::Cost;
+use super::Dir;
+use super::Error;
+use super::Grid;
+use super::MapPos;
+use super::Sqrid;
+
+/* Bfs01Iterator **************************************************************/
+
+/// Internal 0-1 BFS iterator
+///
+/// The movement function must only return step costs of `0` or `1`;
+/// the iterator panics if it returns a greater cost.
+#[derive(Debug, Clone)]
+pub struct Bfs01Iterator<
+    F,
+    MapPosUsize,
+    P: PosT,
+    const D: bool,
+    const WORDS: usize,
+    const SIZE: usize,
+> {
+    cost: MapPosUsize,
+    frontier: VecDeque<(usize, (P, Dir))>,
+    go: F,
+}
+
+impl<F, MapPosUsize, P: PosT, const D: bool, const WORDS: usize, const SIZE: usize>
+    Bfs01Iterator<F, MapPosUsize, P, D, WORDS, SIZE>
+{
+    /// Create a new 0-1 BFS iterator
+    ///
+    /// This is used internally to yield coordinates in cost order.
+    pub fn new(go: F, orig: &P) -> Bfs01Iterator<F, MapPosUsize, P, D, WORDS, SIZE>
+    where
+        F: Fn(P, Dir) -> Option<(P, Cost)>,
+        MapPosUsize: MapPos<usize, P, WORDS, SIZE> + Default,
+        P: Copy,
+    {
+        let mut it = Bfs01Iterator {
+            cost: MapPosUsize::new(usize::MAX),
+            frontier: VecDeque::default(),
+            go,
+        };
+        it.frontier.push_back((0, (*orig, Dir::default())));
+        it.cost.set(*orig, 0);
+        it
+    }
+}
+
+impl<F, MapPosUsize, P: PosT, const D: bool, const WORDS: usize, const SIZE: usize> Iterator
+    for Bfs01Iterator<F, MapPosUsize, P, D, WORDS, SIZE>
+where
+    F: Fn(P, Dir) -> Option<(P, Cost)>,
+    MapPosUsize: MapPos<usize, P, WORDS, SIZE>,
+    P: Copy,
+{
+    type Item = (P, Dir);
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((cost, mov)) = self.frontier.pop_front() {
+            let pos = mov.0;
+            if cost > *self.cost.get(&pos) {
+                // Stale entry, pos was already reached with a lower cost
+                continue;
+            }
+            for dir in Dir::iter::<D>() {
+                if let Some((next_pos, costincr)) = (self.go)(pos, dir) {
+                    assert!(costincr <= 1, "0-1 BFS step cost must be 0 or 1");
+                    let newcost = cost + costincr;
+                    if newcost < *self.cost.get(&next_pos) {
+                        self.cost.set(next_pos, newcost);
+                        if costincr == 0 {
+                            self.frontier.push_front((newcost, (next_pos, -dir)));
+                        } else {
+                            self.frontier.push_back((newcost, (next_pos, -dir)));
+                        }
+                    }
+                }
+            }
+            return Some(mov);
+        }
+        None
+    }
+}
+
+/* Generic interface **********************************************************/
+
+/// Make a 0-1 BFS search, return the "came from" direction [`MapPos`]
+///
+/// Generic interface over types that implement [`MapPos`] for [`Dir`] and `usize`
+pub fn search_mapmov<
+    F,
+    MapPosDir,
+    MapPosUsize,
+    P,
+    const D: bool,
+    const WORDS: usize,
+    const SIZE: usize,
+>(
+    go: F,
+    orig: &P,
+    dest: &P,
+) -> Result<MapPosDir, Error>
+where
+    F: Fn(P, Dir) -> Option<(P, Cost)>,
+    MapPosDir: MapPos<Option<Dir>, P, WORDS, SIZE> + Default,
+    MapPosUsize: MapPos<usize, P, WORDS, SIZE> + Default,
+    P: PosT,
+    P: Copy,
+{
+    let mut from = MapPosDir::new(None);
+    for (pos, dir) in Bfs01Iterator::<F, MapPosUsize, P, D, WORDS, SIZE>::new(go, orig) {
+        from.set(pos, Some(dir));
+        if pos == *dest {
+            return Ok(from);
+        }
+    }
+    Err(Error::DestinationUnreachable)
+}
+
+/// Makes a 0-1 BFS search, returns the path as a `Vec<Dir>`
+///
+/// Generic interface over types that implement [`MapPos`] for [`Dir`] and `usize`
+///
+/// This is essentially [`search_mapmov`] followed by a call to
+/// [`camefrom_into_path`](crate::camefrom_into_path).
+pub fn search_path<
+    F,
+    MapPosDir,
+    MapPosUsize,
+    P,
+    const D: bool,
+    const WORDS: usize,
+    const SIZE: usize,
+>(
+    go: F,
+    orig: &P,
+    dest: &P,
+) -> Result<Vec<Dir>, Error>
+where
+    F: Fn(P, Dir) -> Option<(P, Cost)>,
+    MapPosDir: MapPos<Option<Dir>, P, WORDS, SIZE> + Default,
+    MapPosUsize: MapPos<usize, P, WORDS, SIZE> + Default,
+    P: PosT,
+    P: core::ops::Add<Dir, Output = Result<P, Error>>,
+    P: Copy,
+{
+    let mapmov = search_mapmov::<F, MapPosDir, MapPosUsize, P, D, WORDS, SIZE>(go, orig, dest)?;
+    camefrom_into_path(mapmov, orig, dest)
+}
+
+/* Parameterized interface ****************************************************/
+
+/// Makes a 0-1 BFS search using [`Grid`], returns the path as a `Vec<Dir>`
+///
+/// The grids are allocated on the heap when they are larger than
+/// [`GRID_BOX_THRESHOLD`](crate::GRID_BOX_THRESHOLD).
+pub fn search_path_grid<F, P, const D: bool, const WORDS: usize, const SIZE: usize>(
+    go: F,
+    orig: &P,
+    dest: &P,
+) -> Result<Vec<Dir>, Error>
+where
+    F: Fn(P, Dir) -> Option<(P, Cost)>,
+    P: PosT,
+    P: core::ops::Add<Dir, Output = Result<P, Error>>,
+    P: Copy,
+{
+    if Grid::<usize, P, SIZE>::SHOULD_BOX {
+        search_path::<
+            F,
+            Box<Grid<Option<Dir>, P, SIZE>>,
+            Box<Grid<usize, P, SIZE>>,
+            P,
+            D,
+            WORDS,
+            SIZE,
+        >(go, orig, dest)
+    } else {
+        search_path::<F, Grid<Option<Dir>, P, SIZE>, Grid<usize, P, SIZE>, P, D, WORDS, SIZE>(
+            go, orig, dest,
+        )
+    }
+}
+
+/// Makes a 0-1 BFS search using the [`HashMap`](std::collections::HashMap) type,
+/// returns the path as a `Vec<Dir>`
+#[cfg(feature = "std")]
+pub fn search_path_hash<F, P, const D: bool, const WORDS: usize, const SIZE: usize>(
+    go: F,
+    orig: &P,
+    dest: &P,
+) -> Result<Vec<Dir>, Error>
+where
+    F: Fn(P, Dir) -> Option<(P, Cost)>,
+    P: PosT,
+    P: core::ops::Add<Dir, Output = Result<P, Error>>,
+    P: Eq + core::hash::Hash,
+    P: Copy,
+{
+    search_path::<
+        F,
+        (std::collections::HashMap<P, Option<Dir>>, Option<Dir>),
+        (std::collections::HashMap<P, usize>, usize),
+        P,
+        D,
+        WORDS,
+        SIZE,
+    >(go, orig, dest)
+}
+
+/// Makes a 0-1 BFS search using the [`BTreeMap`](alloc::collections::BTreeMap) type,
+/// returns the path as a `Vec<Dir>`
+pub fn search_path_btree<F, P, const D: bool, const WORDS: usize, const SIZE: usize>(
+    go: F,
+    orig: &P,
+    dest: &P,
+) -> Result<Vec<Dir>, Error>
+where
+    F: Fn(P, Dir) -> Option<(P, Cost)>,
+    P: PosT,
+    P: core::ops::Add<Dir, Output = Result<P, Error>>,
+    P: Ord,
+    P: Copy,
+{
+    search_path::<
+        F,
+        (collections::BTreeMap<P, Option<Dir>>, Option<Dir>),
+        (collections::BTreeMap<P, usize>, usize),
+        P,
+        D,
+        WORDS,
+        SIZE,
+    >(go, orig, dest)
+}
+
+/* Sqrid plugin: **************************************************************/
+
+impl<const W: u16, const H: u16, const D: bool, const WORDS: usize, const SIZE: usize>
+    Sqrid<W, H, D, WORDS, SIZE>
+{
+    /// Perform a 0-1 breadth-first search;
+    /// see [`bfs01`](crate::bfs01).
+    pub fn bfs01_path<F, P>(go: F, orig: &P, dest: &P) -> Result<Vec<Dir>, Error>
+    where
+        F: Fn(P, Dir) -> Option<(P, Cost)>,
+        P: PosT,
+        P: core::ops::Add<Dir, Output = Result<P, Error>>,
+        P: Copy,
+    {
+        Self::bfs01_path_grid::<F, P>(go, orig, dest)
+    }
+
+    /// Perform a 0-1 breadth-first search using a [`Grid`] internally;
+    /// see [`bfs01`](crate::bfs01).
+    pub fn bfs01_path_grid<F, P>(go: F, orig: &P, dest: &P) -> Result<Vec<Dir>, Error>
+    where
+        F: Fn(P, Dir) -> Option<(P, Cost)>,
+        P: PosT,
+        P: core::ops::Add<Dir, Output = Result<P, Error>>,
+        P: Copy,
+    {
+        search_path_grid::<F, P, D, WORDS, SIZE>(go, orig, dest)
+    }
+
+    /// Perform a 0-1 breadth-first search using a
+    /// [`HashMap`](std::collections::HashMap) internally;
+    /// see [`bfs01`](crate::bfs01).
+    #[cfg(feature = "std")]
+    pub fn bfs01_path_hash<F, P>(go: F, orig: &P, dest: &P) -> Result<Vec<Dir>, Error>
+    where
+        F: Fn(P, Dir) -> Option<(P, Cost)>,
+        P: PosT,
+        P: core::ops::Add<Dir, Output = Result<P, Error>>,
+        P: Eq + core::hash::Hash,
+        P: Copy,
+    {
+        search_path_hash::<F, P, D, WORDS, SIZE>(go, orig, dest)
+    }
+
+    /// Perform a 0-1 breadth-first search using a
+    /// [`BTreeMap`](alloc::collections::BTreeMap) internally;
+    /// see [`bfs01`](crate::bfs01).
+    pub fn bfs01_path_btree<F, P>(go: F, orig: &P, dest: &P) -> Result<Vec<Dir>, Error>
+    where
+        F: Fn(P, Dir) -> Option<(P, Cost)>,
+        P: PosT,
+        P: core::ops::Add<Dir, Output = Result<P, Error>>,
+        P: Ord,
+        P: Copy,
+    {
+        search_path_btree::<F, P, D, WORDS, SIZE>(go, orig, dest)
+    }
+}
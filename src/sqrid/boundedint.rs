@@ -17,8 +17,8 @@
 
 use super::error::Error;
 
-use std::convert::TryFrom;
-use std::fmt::Debug;
+use core::convert::TryFrom;
+use core::fmt::Debug;
 
 macro_rules! into_or_oob {
     ($e:expr) => {
@@ -32,7 +32,7 @@ macro_rules! into_or_oob {
 /// possible value.
 pub trait BoundedInt:
     Debug
-    + std::fmt::Display
+    + core::fmt::Display
     + Default
     + Eq
     + PartialOrd
@@ -53,7 +53,7 @@ pub trait BoundedInt:
     + TryFrom<i64>
     + TryFrom<i128>
 where
-    Self: std::marker::Sized,
+    Self: core::marker::Sized,
 {
     /// The smallest value that can be represented by this integer type.
     const MIN: Self;
@@ -259,14 +259,14 @@ macro_rules! boundedint_type_create {
             }
         }
 
-        impl<const MIN: $type, const MAX: $type> std::fmt::Debug for $name<MIN, MAX> {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        impl<const MIN: $type, const MAX: $type> core::fmt::Debug for $name<MIN, MAX> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
                 write!(f, "{}", self.0)
             }
         }
 
-        impl<const MIN: $type, const MAX: $type> std::fmt::Display for $name<MIN, MAX> {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        impl<const MIN: $type, const MAX: $type> core::fmt::Display for $name<MIN, MAX> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
                 write!(f, "{}", self.0)
             }
         }
@@ -0,0 +1,133 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+//! Cycle detection for repeated transformations
+//!
+//! Applying a transformation - like an automaton step or a rotation - a huge number of times
+//! is usually only feasible because the state eventually repeats.
+#![cfg_attr(
+    feature = "std",
+    doc = "[`find_cycle`] applies the transformation until that happens, using a [`HashMap`]",
+    doc = "of the states seen so far, which works with [`Grid`](crate::Grid) and",
+    doc = "[`Gridbool`](crate::Gridbool) as they implement [`Hash`] and [`Eq`]."
+)]
+//! [`find_cycle_brent`] uses Brent's algorithm, which needs
+//! only [`Eq`] and keeps just two states in memory, at the cost of calling the
+//! transformation more times.
+//!
+//! Both return a [`Cycle`], that can then extrapolate the state after any number of steps.
+//!
+//! Example usage:
+//!
+//! ```
+//! type Sqrid = sqrid::sqrid_create!(2, 2, false);
+//! type Pos = sqrid::pos_create!(Sqrid);
+//! type Grid = sqrid::grid_create!(Sqrid, u8);
+//!
+//! let mut grid = Grid::default();
+//! grid[Pos::TOP_LEFT] = 1;
+//! let rotate = |g: &Grid| {
+//!     let mut g = *g;
+//!     g.rotate_cw();
+//!     g
+//! };
+//! let cycle = sqrid::find_cycle_brent(&grid, rotate);
+//! assert_eq!((cycle.prefix, cycle.length), (0, 4));
+//! let state = cycle.state_at(&grid, rotate, 1_000_000_001);
+//! assert_eq!(state[Pos::TOP_RIGHT], 1);
+//! ```
+
+use core::hash::Hash;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+/// A cycle found in the sequence of states generated by a transformation
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cycle {
+    /// Number of steps before the first state of the cycle
+    pub prefix: usize,
+    /// Number of steps in the cycle
+    pub length: usize,
+}
+
+impl Cycle {
+    /// Return the smallest number of steps that generates the same
+    /// state as `n` steps
+    pub fn index(&self, n: usize) -> usize {
+        if n < self.prefix {
+            n
+        } else {
+            self.prefix + (n - self.prefix) % self.length
+        }
+    }
+
+    /// Return the state after `n` steps, by applying `step` to
+    /// `initial` only [`Cycle::index(n)`](Cycle::index) times
+    pub fn state_at<T: Clone>(&self, initial: &T, mut step: impl FnMut(&T) -> T, n: usize) -> T {
+        (0..self.index(n)).fold(initial.clone(), |state, _| step(&state))
+    }
+}
+
+/// Find the cycle in the states generated by applying `step` repeatedly
+/// to `initial`, using a [`HashMap`]
+///
+/// This function doesn't return if the states never repeat.
+#[cfg(feature = "std")]
+pub fn find_cycle<T>(initial: &T, mut step: impl FnMut(&T) -> T) -> Cycle
+where
+    T: Clone + Eq + Hash,
+{
+    let mut seen = HashMap::<T, usize>::new();
+    let mut state = initial.clone();
+    let mut i = 0;
+    loop {
+        let next = step(&state);
+        if let Some(first) = seen.insert(state, i) {
+            return Cycle {
+                prefix: first,
+                length: i - first,
+            };
+        }
+        state = next;
+        i += 1;
+    }
+}
+
+/// Find the cycle in the states generated by applying `step` repeatedly
+/// to `initial`, using Brent's algorithm
+///
+/// This function doesn't return if the states never repeat.
+pub fn find_cycle_brent<T>(initial: &T, mut step: impl FnMut(&T) -> T) -> Cycle
+where
+    T: Clone + Eq,
+{
+    // Find the length of the cycle by moving the tortoise to the
+    // hare's position at every power of two:
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(initial);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+    // Find the start of the cycle with the hare `length` steps ahead:
+    let mut tortoise = initial.clone();
+    let mut hare = (0..length).fold(initial.clone(), |state, _| step(&state));
+    let mut prefix = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix += 1;
+    }
+    Cycle { prefix, length }
+}
@@ -0,0 +1,379 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+//! Dial's algorithm module: uniform-cost search with a bucket queue
+//!
+//! This algorithm takes a movement-cost function, an origin and a destination, and figures out
+//! the path with the lowest cost by using
+//! [Dial's algorithm](https://en.wikipedia.org/wiki/Bucket_queue#Applications), a variation of
+//! [`ucs`](crate::ucs) that keeps the frontier in a queue of buckets indexed by cost instead of
+//! in a binary heap.
+//! It should be used instead of `ucs` when the cost of each step is a small integer, such as
+//! the `1..=9` of digit grids, as it avoids the logarithmic cost of the heap operations. The
+//! memory used by the buckets is proportional to the largest step cost.
+//!
+//! Check out [`bfs01`](crate::bfs01) if the costs are only `0` or `1`.
+//!
+//! The base of this module is the [`DialIterator`], which yields [`super::pos::Pos`]
+//! coordinates in cost order. That iterator is used by [`search_mapmov`] to build an unsorted
+//! `super::pos::Pos`-indexed map of [`Dir`] directions, which can then transformed into a
+//! vector of directions by [`crate::camefrom_into_path`]. The complete search process is
+//! wrapped by [`search_path`].
+//!
+//! All these functions can be called directly, but that's a bit inconvenient, as they require
+//! several generic parameters. An easier alternative is provided by the wrappers plugged into
+//! the [`Sqrid`] type:
+//! - [`Sqrid::dial_path_grid`]
+#![cfg_attr(feature = "std", doc = " - [`Sqrid::dial_path_hash`]")]
+//! - [`Sqrid::dial_path_btree`]
+//! - [`Sqrid::dial_path`]: alias for `dial_path_grid`.
+//!
+//! Example of recommended usage:
+//!
+//! ```
+//! type Sqrid = sqrid::sqrid_create!(3, 3, false);
+//! type Pos = sqrid::pos_create!(Sqrid);
+//!
+//! fn traverse(position: Pos, direction: sqrid::Dir) -> Option<(Pos, usize)> {
+//!     let next_position = (position + direction).ok()?;
+//!     // Use the coordinates as digits for the cost:
+//!     let cost = 1 + (next_position.x() + next_position.y()) as usize % 9;
+//!     Some((next_position, cost))
+//! }
+//!
+//! if let Ok(path) = Sqrid::dial_path(traverse, &Pos::TOP_LEFT,
+//!                                    &Pos::BOTTOM_RIGHT) {
+//!     println!("path: {:?}", path);
+//! }
+//! ```
+
+use alloc::boxed::Box;
+use alloc::collections;
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+
+use super::camefrom_into_path;
+use super::postrait::PosT;
+use super::ucs::Cost;
+use super::Dir;
+use super::Error;
+use super::Grid;
+use super::MapPos;
+use super::Sqrid;
+
+/* DialIterator ***************************************************************/
+
+/// Internal Dial's algorithm iterator
+///
+/// The frontier is a ring of buckets, where the bucket at index `i` holds the positions with
+/// cost `base + i`.
+#[derive(Debug, Clone)]
+pub struct DialIterator<
+    F,
+    MapPosUsize,
+    P: PosT,
+    const D: bool,
+    const WORDS: usize,
+    const SIZE: usize,
+> {
+    cost: MapPosUsize,
+    frontier: VecDeque<Vec<(P, Dir)>>,
+    base: usize,
+    pending: usize,
+    go: F,
+}
+
+impl<F, MapPosUsize, P: PosT, const D: bool, const WORDS: usize, const SIZE: usize>
+    DialIterator<F, MapPosUsize, P, D, WORDS, SIZE>
+{
+    /// Create a new Dial's algorithm iterator
+    ///
+    /// This is used internally to yield coordinates in cost order.
+    pub fn new(go: F, orig: &P) -> DialIterator<F, MapPosUsize, P, D, WORDS, SIZE>
+    where
+        F: Fn(P, Dir) -> Option<(P, Cost)>,
+        MapPosUsize: MapPos<usize, P, WORDS, SIZE> + Default,
+        P: Copy,
+    {
+        let mut it = DialIterator {
+            cost: MapPosUsize::new(usize::MAX),
+            frontier: VecDeque::default(),
+            base: 0,
+            pending: 0,
+            go,
+        };
+        it.push(0, (*orig, Dir::default()));
+        it.cost.set(*orig, 0);
+        it
+    }
+
+    /// Pop the next entry of the lowest-cost non-empty bucket, along with its cost
+    fn pop(&mut self) -> Option<(usize, (P, Dir))> {
+        if self.pending == 0 {
+            return None;
+        }
+        loop {
+            if let Some(mov) = self.frontier[0].pop() {
+                self.pending -= 1;
+                return Some((self.base, mov));
+            }
+            // Recycle the empty bucket, keeping its allocation:
+            self.frontier.rotate_left(1);
+            self.base += 1;
+        }
+    }
+
+    /// Push an entry into the bucket that corresponds to the provided cost
+    fn push(&mut self, cost: usize, mov: (P, Dir)) {
+        let index = cost - self.base;
+        if index >= self.frontier.len() {
+            self.frontier.resize_with(index + 1, Vec::new);
+        }
+        self.frontier[index].push(mov);
+        self.pending += 1;
+    }
+}
+
+impl<F, MapPosUsize, P: PosT, const D: bool, const WORDS: usize, const SIZE: usize> Iterator
+    for DialIterator<F, MapPosUsize, P, D, WORDS, SIZE>
+where
+    F: Fn(P, Dir) -> Option<(P, Cost)>,
+    MapPosUsize: MapPos<usize, P, WORDS, SIZE>,
+    P: Copy,
+{
+    type Item = (P, Dir);
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((cost, mov)) = self.pop() {
+            let pos = mov.0;
+            if cost > *self.cost.get(&pos) {
+                // Stale entry, pos was already reached with a lower cost
+                continue;
+            }
+            for dir in Dir::iter::<D>() {
+                if let Some((next_pos, costincr)) = (self.go)(pos, dir) {
+                    let newcost = cost + costincr;
+                    if newcost < *self.cost.get(&next_pos) {
+                        self.cost.set(next_pos, newcost);
+                        self.push(newcost, (next_pos, -dir));
+                    }
+                }
+            }
+            return Some(mov);
+        }
+        None
+    }
+}
+
+/* Generic interface **********************************************************/
+
+/// Make a Dial's algorithm search, return the "came from" direction [`MapPos`]
+///
+/// Generic interface over types that implement [`MapPos`] for [`Dir`] and `usize`
+pub fn search_mapmov<
+    F,
+    MapPosDir,
+    MapPosUsize,
+    P,
+    const D: bool,
+    const WORDS: usize,
+    const SIZE: usize,
+>(
+    go: F,
+    orig: &P,
+    dest: &P,
+) -> Result<MapPosDir, Error>
+where
+    F: Fn(P, Dir) -> Option<(P, Cost)>,
+    MapPosDir: MapPos<Option<Dir>, P, WORDS, SIZE> + Default,
+    MapPosUsize: MapPos<usize, P, WORDS, SIZE> + Default,
+    P: PosT,
+    P: Copy,
+{
+    let mut from = MapPosDir::new(None);
+    for (pos, dir) in DialIterator::<F, MapPosUsize, P, D, WORDS, SIZE>::new(go, orig) {
+        from.set(pos, Some(dir));
+        if pos == *dest {
+            return Ok(from);
+        }
+    }
+    Err(Error::DestinationUnreachable)
+}
+
+/// Makes a Dial's algorithm search, returns the path as a `Vec<Dir>`
+///
+/// Generic interface over types that implement [`MapPos`] for [`Dir`] and `usize`
+///
+/// This is essentially [`search_mapmov`] followed by a call to
+/// [`camefrom_into_path`](crate::camefrom_into_path).
+pub fn search_path<
+    F,
+    MapPosDir,
+    MapPosUsize,
+    P,
+    const D: bool,
+    const WORDS: usize,
+    const SIZE: usize,
+>(
+    go: F,
+    orig: &P,
+    dest: &P,
+) -> Result<Vec<Dir>, Error>
+where
+    F: Fn(P, Dir) -> Option<(P, Cost)>,
+    MapPosDir: MapPos<Option<Dir>, P, WORDS, SIZE> + Default,
+    MapPosUsize: MapPos<usize, P, WORDS, SIZE> + Default,
+    P: PosT,
+    P: core::ops::Add<Dir, Output = Result<P, Error>>,
+    P: Copy,
+{
+    let mapmov = search_mapmov::<F, MapPosDir, MapPosUsize, P, D, WORDS, SIZE>(go, orig, dest)?;
+    camefrom_into_path(mapmov, orig, dest)
+}
+
+/* Parameterized interface ****************************************************/
+
+/// Makes a Dial's algorithm search using [`Grid`], returns the path as a `Vec<Dir>`
+///
+/// The grids are allocated on the heap when they are larger than
+/// [`GRID_BOX_THRESHOLD`](crate::GRID_BOX_THRESHOLD).
+pub fn search_path_grid<F, P, const D: bool, const WORDS: usize, const SIZE: usize>(
+    go: F,
+    orig: &P,
+    dest: &P,
+) -> Result<Vec<Dir>, Error>
+where
+    F: Fn(P, Dir) -> Option<(P, Cost)>,
+    P: PosT,
+    P: core::ops::Add<Dir, Output = Result<P, Error>>,
+    P: Copy,
+{
+    if Grid::<usize, P, SIZE>::SHOULD_BOX {
+        search_path::<
+            F,
+            Box<Grid<Option<Dir>, P, SIZE>>,
+            Box<Grid<usize, P, SIZE>>,
+            P,
+            D,
+            WORDS,
+            SIZE,
+        >(go, orig, dest)
+    } else {
+        search_path::<F, Grid<Option<Dir>, P, SIZE>, Grid<usize, P, SIZE>, P, D, WORDS, SIZE>(
+            go, orig, dest,
+        )
+    }
+}
+
+/// Makes a Dial's algorithm search using the [`HashMap`](std::collections::HashMap) type,
+/// returns the path as a `Vec<Dir>`
+#[cfg(feature = "std")]
+pub fn search_path_hash<F, P, const D: bool, const WORDS: usize, const SIZE: usize>(
+    go: F,
+    orig: &P,
+    dest: &P,
+) -> Result<Vec<Dir>, Error>
+where
+    F: Fn(P, Dir) -> Option<(P, Cost)>,
+    P: PosT,
+    P: core::ops::Add<Dir, Output = Result<P, Error>>,
+    P: Eq + core::hash::Hash,
+    P: Copy,
+{
+    search_path::<
+        F,
+        (std::collections::HashMap<P, Option<Dir>>, Option<Dir>),
+        (std::collections::HashMap<P, usize>, usize),
+        P,
+        D,
+        WORDS,
+        SIZE,
+    >(go, orig, dest)
+}
+
+/// Makes a Dial's algorithm search using the [`BTreeMap`](alloc::collections::BTreeMap) type,
+/// returns the path as a `Vec<Dir>`
+pub fn search_path_btree<F, P, const D: bool, const WORDS: usize, const SIZE: usize>(
+    go: F,
+    orig: &P,
+    dest: &P,
+) -> Result<Vec<Dir>, Error>
+where
+    F: Fn(P, Dir) -> Option<(P, Cost)>,
+    P: PosT,
+    P: core::ops::Add<Dir, Output = Result<P, Error>>,
+    P: Ord,
+    P: Copy,
+{
+    search_path::<
+        F,
+        (collections::BTreeMap<P, Option<Dir>>, Option<Dir>),
+        (collections::BTreeMap<P, usize>, usize),
+        P,
+        D,
+        WORDS,
+        SIZE,
+    >(go, orig, dest)
+}
+
+/* Sqrid plugin: **************************************************************/
+
+impl<const W: u16, const H: u16, const D: bool, const WORDS: usize, const SIZE: usize>
+    Sqrid<W, H, D, WORDS, SIZE>
+{
+    /// Perform a uniform-cost search with Dial's algorithm;
+    /// see [`dial`](crate::dial).
+    pub fn dial_path<F, P>(go: F, orig: &P, dest: &P) -> Result<Vec<Dir>, Error>
+    where
+        F: Fn(P, Dir) -> Option<(P, Cost)>,
+        P: PosT,
+        P: core::ops::Add<Dir, Output = Result<P, Error>>,
+        P: Copy,
+    {
+        Self::dial_path_grid::<F, P>(go, orig, dest)
+    }
+
+    /// Perform a uniform-cost search with Dial's algorithm using a [`Grid`] internally;
+    /// see [`dial`](crate::dial).
+    pub fn dial_path_grid<F, P>(go: F, orig: &P, dest: &P) -> Result<Vec<Dir>, Error>
+    where
+        F: Fn(P, Dir) -> Option<(P, Cost)>,
+        P: PosT,
+        P: core::ops::Add<Dir, Output = Result<P, Error>>,
+        P: Copy,
+    {
+        search_path_grid::<F, P, D, WORDS, SIZE>(go, orig, dest)
+    }
+
+    /// Perform a uniform-cost search with Dial's algorithm using a
+    /// [`HashMap`](std::collections::HashMap) internally;
+    /// see [`dial`](crate::dial).
+    #[cfg(feature = "std")]
+    pub fn dial_path_hash<F, P>(go: F, orig: &P, dest: &P) -> Result<Vec<Dir>, Error>
+    where
+        F: Fn(P, Dir) -> Option<(P, Cost)>,
+        P: PosT,
+        P: core::ops::Add<Dir, Output = Result<P, Error>>,
+        P: Eq + core::hash::Hash,
+        P: Copy,
+    {
+        search_path_hash::<F, P, D, WORDS, SIZE>(go, orig, dest)
+    }
+
+    /// Perform a uniform-cost search with Dial's algorithm using a
+    /// [`BTreeMap`](alloc::collections::BTreeMap) internally;
+    /// see [`dial`](crate::dial).
+    pub fn dial_path_btree<F, P>(go: F, orig: &P, dest: &P) -> Result<Vec<Dir>, Error>
+    where
+        F: Fn(P, Dir) -> Option<(P, Cost)>,
+        P: PosT,
+        P: core::ops::Add<Dir, Output = Result<P, Error>>,
+        P: Ord,
+        P: Copy,
+    {
+        search_path_btree::<F, P, D, WORDS, SIZE>(go, orig, dest)
+    }
+}
@@ -8,10 +8,10 @@
 //! Direction data structure [`Dir`] that represents movement, and
 //! related functionality.
 
-use std::convert;
-use std::fmt;
-use std::ops;
-use std::str::FromStr;
+use core::convert;
+use core::fmt;
+use core::ops;
+use core::str::FromStr;
 
 use super::boundedint::BoundedInt;
 use super::error::Error;
@@ -299,7 +299,7 @@ impl FromStr for Dir {
 
 impl<T> convert::TryFrom<(T, T)> for Dir
 where
-    Dir: for<'a> std::convert::TryFrom<&'a (T, T), Error = Error>,
+    Dir: for<'a> core::convert::TryFrom<&'a (T, T), Error = Error>,
 {
     type Error = Error;
     fn try_from(xy: (T, T)) -> Result<Self, Self::Error> {
@@ -7,8 +7,8 @@
 
 //! sqrid errors
 
-use std::error;
-use std::fmt;
+use core::error;
+use core::fmt;
 
 /// sqrid errors enum
 ///
@@ -31,6 +31,17 @@ pub enum Error {
     DestinationUnreachable,
     /// An empty list or iterator was passed where one was not expected
     Empty,
+    /// A [`super::Shape`] overlaps positions that are already set
+    Collision,
+    /// A path that should be a closed loop doesn't end where it
+    /// starts
+    NotClosed,
+    /// Attempted to parse an invalid or unsupported netpbm image
+    ImageParseError,
+    /// A search was stopped by its [`super::SearchLimits`]; see
+    /// [`super::LimitsError`] for the variant that carries the partial
+    /// "came from" map
+    BudgetExceeded,
 }
 
 impl error::Error for Error {}
@@ -45,6 +56,10 @@ impl fmt::Display for Error {
             Error::Loop => write!(f, "unexpected loop detected"),
             Error::DestinationUnreachable => write!(f, "destination unreachable"),
             Error::Empty => write!(f, "empty list of iterator"),
+            Error::Collision => write!(f, "shape collides with set positions"),
+            Error::NotClosed => write!(f, "path is not a closed loop"),
+            Error::ImageParseError => write!(f, "invalid netpbm image"),
+            Error::BudgetExceeded => write!(f, "search budget exceeded"),
         }
     }
 }
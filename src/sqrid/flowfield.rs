@@ -0,0 +1,168 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+//! Flow fields: the direction towards a goal from every position
+//!
+//! When many agents are heading to the same goal, it's cheaper to run a single search from
+//! the goal than one search per agent. [`flow_field`] does that with a reverse uniform-cost
+//! search using [`UcsIterator`]: it calls the movement-cost function "backwards" to find
+//! which positions can reach each one, and returns a [`FlowField`] with the direction every
+//! position should take to get to the goal, along with the total cost of doing so.
+//!
+//! The [`FlowField::follow`] method walks the field from a position to the goal and returns
+//! the path as a `Vec<Dir>`, similarly to [`camefrom_into_path`](crate::camefrom_into_path).
+//!
+//! Example usage:
+//!
+//! ```
+//! type Sqrid = sqrid::sqrid_create!(3, 3, false);
+//! type Pos = sqrid::pos_create!(Sqrid);
+//!
+//! fn traverse(position: Pos, direction: sqrid::Dir) -> Option<(Pos, usize)> {
+//!     let next_position = (position + direction).ok()?;
+//!     Some((next_position, 1))
+//! }
+//!
+//! let field = Sqrid::flow_field(traverse, &Pos::BOTTOM_RIGHT);
+//! assert_eq!(field.costs[Pos::TOP_LEFT], 6);
+//! assert_eq!(field.dirs[Pos::BOTTOM_RIGHT], None);
+//! let path = field.follow(&Pos::TOP_LEFT)?;
+//! assert_eq!(path.len(), 6);
+//! # Ok::<(), sqrid::Error>(())
+//! ```
+
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+use super::postrait::PosT;
+use super::ucs::Cost;
+use super::ucs::UcsIterator;
+use super::Dir;
+use super::Error;
+use super::Grid;
+use super::Sqrid;
+
+/* FlowField ******************************************************************/
+
+/// Directions and costs towards a goal from every position
+///
+/// Created by [`flow_field`]. The grids are always allocated on the heap,
+/// as flow fields are usually built for large grids.
+#[derive(Debug, Clone)]
+pub struct FlowField<P: PosT, const SIZE: usize> {
+    /// The goal of the field
+    pub goal: P,
+    /// Direction to take from each position to get closer to the goal;
+    /// `None` for the goal itself and for positions that can't reach it.
+    pub dirs: Box<Grid<Option<Dir>, P, SIZE>>,
+    /// Total cost to reach the goal from each position;
+    /// `usize::MAX` for positions that can't reach it.
+    pub costs: Box<Grid<Cost, P, SIZE>>,
+}
+
+impl<P: PosT, const SIZE: usize> FlowField<P, SIZE> {
+    /// Return true if the goal can be reached from the provided position
+    pub fn reaches(&self, pos: &P) -> bool {
+        self.costs[pos] != usize::MAX
+    }
+
+    /// Follow the field from the provided position to the goal,
+    /// returning the path as a `Vec<Dir>`
+    pub fn follow(&self, pos: &P) -> Result<Vec<Dir>, Error>
+    where
+        P: Copy,
+        P: PartialEq,
+        P: core::ops::Add<Dir, Output = Result<P, Error>>,
+    {
+        if !self.reaches(pos) {
+            return Err(Error::DestinationUnreachable);
+        }
+        let mut path = Vec::with_capacity(pos.manhattan(&self.goal));
+        let mut pos = *pos;
+        while pos != self.goal {
+            let dir = self.dirs[pos].ok_or(Error::InvalidMovement)?;
+            path.push(dir);
+            pos = (pos + dir).or(Err(Error::InvalidMovement))?;
+            if path.len() > SIZE {
+                return Err(Error::Loop);
+            }
+        }
+        Ok(path)
+    }
+
+    fn fill<I>(&mut self, it: I)
+    where
+        I: Iterator<Item = (P, Dir, Cost)>,
+        P: PartialEq,
+    {
+        for (pos, dir, cost) in it {
+            if pos != self.goal {
+                self.dirs[pos] = Some(dir);
+            }
+            self.costs[pos] = cost;
+        }
+    }
+}
+
+/* Generic interface **********************************************************/
+
+/// Create the [`FlowField`] towards `goal` by running a reverse UCS from it
+///
+/// `go` is the same movement-cost function used by [`ucs`](crate::ucs): it returns the
+/// position reached by moving from the first argument in the given direction, along with the
+/// cost of the step.
+pub fn flow_field<F, P, const D: bool, const WORDS: usize, const SIZE: usize>(
+    go: F,
+    goal: &P,
+) -> FlowField<P, SIZE>
+where
+    F: Fn(P, Dir) -> Option<(P, Cost)>,
+    P: PosT,
+    P: core::ops::Add<Dir, Output = Result<P, Error>>,
+    P: Ord,
+    P: Copy,
+{
+    // Moving from `pos` in `dir` in the reverse search is moving
+    // from the result in `-dir` in the original one:
+    let goback = |pos: P, dir: Dir| {
+        let prev = (pos + dir).ok()?;
+        let (next, cost) = go(prev, -dir)?;
+        (next == pos).then_some((prev, cost))
+    };
+    let mut field = FlowField {
+        goal: *goal,
+        dirs: Grid::new_boxed(None),
+        costs: Grid::new_boxed(usize::MAX),
+    };
+    if Grid::<usize, P, SIZE>::SHOULD_BOX {
+        field.fill(
+            UcsIterator::<_, Box<Grid<usize, P, SIZE>>, P, D, WORDS, SIZE>::new(goback, goal),
+        );
+    } else {
+        field.fill(UcsIterator::<_, Grid<usize, P, SIZE>, P, D, WORDS, SIZE>::new(goback, goal));
+    }
+    field
+}
+
+/* Sqrid plugin: **************************************************************/
+
+impl<const W: u16, const H: u16, const D: bool, const WORDS: usize, const SIZE: usize>
+    Sqrid<W, H, D, WORDS, SIZE>
+{
+    /// Create the [`FlowField`] towards `goal`;
+    /// see [`flowfield`](crate::flowfield).
+    pub fn flow_field<F, P>(go: F, goal: &P) -> FlowField<P, SIZE>
+    where
+        F: Fn(P, Dir) -> Option<(P, Cost)>,
+        P: PosT,
+        P: core::ops::Add<Dir, Output = Result<P, Error>>,
+        P: Ord,
+        P: Copy,
+    {
+        flow_field::<F, P, D, WORDS, SIZE>(go, goal)
+    }
+}
@@ -10,14 +10,28 @@
 //! This submodule has the [`Grid`] type and the associated
 //! functionality.
 
-use std::convert;
-use std::fmt;
-use std::iter;
-use std::ops;
-
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::convert;
+use core::fmt;
+use core::iter;
+use core::ops;
+
+use super::dir::Dir;
 use super::error::Error;
 use super::pos::Pos;
 use super::postrait::PosT;
+use super::symmetry::Symmetry;
+
+/// Size in bytes above which the `search_path_grid` functions
+/// allocate their grids on the heap
+///
+/// See [`Grid::SHOULD_BOX`].
+pub const GRID_BOX_THRESHOLD: usize = 64 * 1024;
 
 /// Assert const generic expressions inside `impl` blocks
 macro_rules! impl_assert {
@@ -48,9 +62,10 @@ macro_rules! grid_create {
 /// A grid is a generic array that can be indexed by a [`Pos`]
 ///
 /// We can also interact with specific lines with [`Grid::line`] and
-/// [`Grid::line_mut`], or with the whole underlying array with
-/// [`as_ref`](std::convert::AsRef::as_ref) and
-/// [`as_mut`](std::convert::AsMut::as_mut).
+/// [`Grid::line_mut`], with columns with [`Grid::column`] and
+/// [`Grid::column_mut`], or with the whole underlying array with
+/// [`as_ref`](core::convert::AsRef::as_ref) and
+/// [`as_mut`](core::convert::AsMut::as_mut).
 ///
 /// At the moment we have to provide a `SIZE` argument = `WIDTH` *
 /// `HEIGHT`. This value is checked at compile time, but can't be
@@ -59,7 +74,8 @@ macro_rules! grid_create {
 /// We can use the [`grid_create`] macro to use a [`Pos`] as a source
 /// of these values.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
-pub struct Grid<T, P: PosT, const SIZE: usize>([T; SIZE], std::marker::PhantomData<P>);
+#[repr(transparent)]
+pub struct Grid<T, P: PosT, const SIZE: usize>([T; SIZE], core::marker::PhantomData<P>);
 
 impl<T, P: PosT, const SIZE: usize> Grid<T, P, SIZE> {
     // Create the _ASSERTS constant to check W * H == SIZE
@@ -79,7 +95,79 @@ impl<T, P: PosT, const SIZE: usize> Grid<T, P, SIZE> {
     where
         T: Copy,
     {
-        Grid([item; SIZE], std::marker::PhantomData)
+        Grid([item; SIZE], core::marker::PhantomData)
+    }
+
+    /// Create a grid by calling `f` with each position, in order
+    ///
+    /// Built on [`core::array::from_fn`], without allocating.
+    pub fn from_fn<F>(mut f: F) -> Self
+    where
+        F: FnMut(P) -> T,
+    {
+        let _ = Self::_ASSERTS;
+        Grid(
+            core::array::from_fn(|i| f(P::tryfrom_usize(i).unwrap())),
+            core::marker::PhantomData,
+        )
+    }
+
+    /// Create a grid by calling the fallible `f` with each position,
+    /// in order, stopping at the first error
+    pub fn try_from_fn<E, F>(mut f: F) -> Result<Self, E>
+    where
+        F: FnMut(P) -> Result<T, E>,
+    {
+        let mut error = None;
+        let items = Grid::<Option<T>, P, SIZE>::from_fn(|pos| {
+            if error.is_some() {
+                return None;
+            }
+            f(pos).map_err(|e| error = Some(e)).ok()
+        });
+        match error {
+            Some(e) => Err(e),
+            None => Ok(items.map(|item| item.unwrap())),
+        }
+    }
+
+    /// True if the grid is larger than [`GRID_BOX_THRESHOLD`] bytes
+    ///
+    /// The `search_path_grid` functions use this to decide whether
+    /// to allocate their grids on the heap.
+    pub const SHOULD_BOX: bool = core::mem::size_of::<Self>() > GRID_BOX_THRESHOLD;
+
+    /// Create a heap-allocated grid filled with clones of the
+    /// provided item
+    ///
+    /// Unlike `Box::new(Grid::repeat(item))`, the grid is never
+    /// built on the stack, which allows us to create grids that
+    /// wouldn't fit in it.
+    pub fn new_boxed(item: T) -> Box<Self>
+    where
+        T: Clone,
+    {
+        Self::boxed_from_vec(vec![item; SIZE])
+    }
+
+    /// Create a heap-allocated grid filled with default values,
+    /// without building it on the stack
+    pub fn default_boxed() -> Box<Self>
+    where
+        T: Default,
+    {
+        Self::boxed_from_vec((0..SIZE).map(|_| T::default()).collect())
+    }
+
+    pub(crate) fn boxed_from_vec(v: Vec<T>) -> Box<Self> {
+        let _ = Self::_ASSERTS;
+        let array: Box<[T; SIZE]> = match v.into_boxed_slice().try_into() {
+            Ok(array) => array,
+            Err(_) => unreachable!(),
+        };
+        // SAFETY: Grid is repr(transparent) over the array, as
+        // PhantomData is zero-sized.
+        unsafe { Box::from_raw(Box::into_raw(array) as *mut Self) }
     }
 
     /// "Dismantle" a Grid into the inner array; consumes self.
@@ -126,6 +214,77 @@ impl<T, P: PosT, const SIZE: usize> Grid<T, P, SIZE> {
         &mut self.0[start..end]
     }
 
+    /// Iterate over the rows of the grid, as slices
+    #[inline]
+    pub fn rows(&self) -> core::slice::ChunksExact<'_, T> {
+        self.0.chunks_exact(P::width())
+    }
+
+    /// Iterate over the rows of the grid, as mut slices
+    #[inline]
+    pub fn rows_mut(&mut self) -> core::slice::ChunksExactMut<'_, T> {
+        self.0.chunks_exact_mut(P::width())
+    }
+
+    /// Iterate over the values of a specific grid column
+    #[inline]
+    pub fn column(&self, x: P::Xtype) -> iter::StepBy<core::slice::Iter<'_, T>> {
+        self.0[coord(x)..].iter().step_by(P::width())
+    }
+
+    /// Iterate mutably over the values of a specific grid column
+    #[inline]
+    pub fn column_mut(&mut self, x: P::Xtype) -> iter::StepBy<core::slice::IterMut<'_, T>> {
+        self.0[coord(x)..].iter_mut().step_by(P::width())
+    }
+
+    /// Iterate over the columns of the grid, each one as an iterator
+    /// over its values
+    pub fn columns(
+        &self,
+    ) -> impl iter::Iterator<Item = iter::StepBy<core::slice::Iter<'_, T>>> + '_ {
+        (0..P::width()).map(|x| self.0[x..].iter().step_by(P::width()))
+    }
+
+    /// Swap the values of two rows
+    pub fn swap_rows(&mut self, y1: P::Ytype, y2: P::Ytype) {
+        let width = P::width();
+        let (y1, y2) = (coord(y1), coord(y2));
+        for x in 0..width {
+            self.0.swap(y1 * width + x, y2 * width + x);
+        }
+    }
+
+    /// Swap the values of two columns
+    pub fn swap_columns(&mut self, x1: P::Xtype, x2: P::Xtype) {
+        let width = P::width();
+        let (x1, x2) = (coord(x1), coord(x2));
+        for y in 0..P::height() {
+            self.0.swap(y * width + x1, y * width + x2);
+        }
+    }
+
+    /// Rotate the values of a row by `n` positions, wrapping around
+    ///
+    /// Positive values of `n` move the values east, negative ones
+    /// west.
+    pub fn rotate_row(&mut self, y: P::Ytype, n: isize) {
+        let n = n.rem_euclid(P::width() as isize) as usize;
+        self.line_mut(y).rotate_right(n);
+    }
+
+    /// Rotate the values of a column by `n` positions, wrapping around
+    ///
+    /// Positive values of `n` move the values south, negative ones
+    /// north.
+    pub fn rotate_column(&mut self, x: P::Xtype, n: isize) {
+        let width = P::width();
+        let x = coord(x);
+        rotate_by_swaps(P::height(), n, |i, j| {
+            self.0.swap(i * width + x, j * width + x)
+        });
+    }
+
     /// Get a reference to an element of the grid.
     ///
     /// We use get_unchecked internally, because we guarantee the
@@ -144,15 +303,22 @@ impl<T, P: PosT, const SIZE: usize> Grid<T, P, SIZE> {
         unsafe { self.0.get_unchecked_mut(pos.to_usize()) }
     }
 
+    /// Iterate over the neighbors of `pos` and their values, along
+    /// with their directions; see [`PosT::neighbors`]
+    pub fn neighbor_values<const D: bool>(&self, pos: &P) -> impl Iterator<Item = (Dir, P, &T)> {
+        pos.neighbors::<D>()
+            .map(|(dir, neighbor)| (dir, neighbor, self.get(&neighbor)))
+    }
+
     /// Returns an iterator over the grid values
     #[inline]
-    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+    pub fn iter(&self) -> core::slice::Iter<'_, T> {
         self.0.iter()
     }
 
     /// Returns an iterator that allows modifying each value
     #[inline]
-    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+    pub fn iter_mut(&mut self) -> core::slice::IterMut<'_, T> {
         self.0.iter_mut()
     }
 
@@ -165,6 +331,47 @@ impl<T, P: PosT, const SIZE: usize> Grid<T, P, SIZE> {
         P::iter().map(move |pos| (pos, &self[pos]))
     }
 
+    /// Create a new grid with the results of calling `f` on every
+    /// value; consumes self
+    pub fn map<U, F>(self, f: F) -> Grid<U, P, SIZE>
+    where
+        F: FnMut(T) -> U,
+    {
+        Grid(self.0.map(f), core::marker::PhantomData)
+    }
+
+    /// Create a new grid with the results of calling `f` on every
+    /// position and corresponding value
+    pub fn map_pos<U, F>(&self, mut f: F) -> Grid<U, P, SIZE>
+    where
+        F: FnMut(P, &T) -> U,
+        P: Copy,
+    {
+        Grid::from_fn(|pos| f(pos, &self[pos]))
+    }
+
+    /// Create a new grid with pairs of the values of `self` and
+    /// `other` at each position
+    pub fn zip<U>(&self, other: &Grid<U, P, SIZE>) -> Grid<(T, U), P, SIZE>
+    where
+        T: Clone,
+        U: Clone,
+    {
+        self.zip_with(other, |t, u| (t.clone(), u.clone()))
+    }
+
+    /// Create a new grid with the results of calling `f` on the
+    /// values of `self` and `other` at each position
+    pub fn zip_with<U, V, F>(&self, other: &Grid<U, P, SIZE>, mut f: F) -> Grid<V, P, SIZE>
+    where
+        F: FnMut(&T, &U) -> V,
+    {
+        Grid(
+            core::array::from_fn(|i| f(&self.0[i], &other.0[i])),
+            core::marker::PhantomData,
+        )
+    }
+
     /// Flip all elements horizontally.
     pub fn flip_h(&mut self) {
         for y in P::iter_y() {
@@ -197,6 +404,176 @@ impl<T, P: PosT, const SIZE: usize> Grid<T, P, SIZE> {
         }
         Ok(())
     }
+
+    /// Slide all movable items towards `dir` until they hit a
+    /// blocking item, another item or the edge of the grid
+    ///
+    /// Items that are neither movable nor blocking are considered
+    /// empty space, and are swapped with the items that move over
+    /// them. Works with all 8 directions; the lines are processed
+    /// as slices when moving horizontally.
+    ///
+    /// Returns the number of items that moved.
+    pub fn slide(
+        &mut self,
+        dir: Dir,
+        is_movable: impl Fn(&T) -> bool,
+        is_blocking: impl Fn(&T) -> bool,
+    ) -> usize {
+        let width = P::width();
+        let height = P::height();
+        let mut moved = 0;
+        if dir == Dir::E || dir == Dir::W {
+            for y in P::iter_y() {
+                let line = self.line_mut(y);
+                moved += if dir == Dir::W {
+                    slide_lane(line, 0..width, &is_movable, &is_blocking)
+                } else {
+                    slide_lane(line, (0..width).rev(), &is_movable, &is_blocking)
+                };
+            }
+            return moved;
+        }
+        // Each lane starts at a position whose next one in `dir` is
+        // outside the grid, and goes backwards:
+        let (dx, dy) = <(isize, isize)>::from(dir);
+        let inside = |x: isize, y: isize| {
+            (0..width as isize).contains(&x) && (0..height as isize).contains(&y)
+        };
+        // Number of positions from `v` to the edge, going backwards
+        // in an axis with direction `d`:
+        let steps = |v: isize, d: isize, len: usize| match d {
+            1 => v as usize + 1,
+            -1 => len - v as usize,
+            _ => usize::MAX,
+        };
+        let stride = dy * width as isize + dx;
+        for i in 0..SIZE {
+            let (x, y) = ((i % width) as isize, (i / width) as isize);
+            if inside(x + dx, y + dy) {
+                continue;
+            }
+            let len = steps(x, dx, width).min(steps(y, dy, height));
+            let lane = (0..len as isize).map(move |k| (i as isize - k * stride) as usize);
+            moved += slide_lane(&mut self.0, lane, &is_movable, &is_blocking);
+        }
+        moved
+    }
+
+    /// Return the top-left positions where the provided pattern
+    /// matches the grid
+    ///
+    /// The pattern must be a grid that is not larger than `self`;
+    /// its positions that have the `wildcard` value match any value.
+    pub fn find_pattern<Q: PosT, const PSIZE: usize>(
+        &self,
+        pattern: &Grid<T, Q, PSIZE>,
+        wildcard: Option<&T>,
+    ) -> Vec<P>
+    where
+        T: PartialEq,
+    {
+        let width = P::width();
+        let pwidth = Q::width();
+        let pheight = Q::height();
+        if pwidth > width || pheight > P::height() {
+            return vec![];
+        }
+        let matches = |start: usize| {
+            (0..pheight).all(|py| {
+                let line = &self.0[start + py * width..][..pwidth];
+                let pline = &pattern.0[py * pwidth..][..pwidth];
+                line.iter()
+                    .zip(pline)
+                    .all(|(value, pvalue)| Some(pvalue) == wildcard || value == pvalue)
+            })
+        };
+        (0..=P::height() - pheight)
+            .flat_map(|y| (0..=width - pwidth).map(move |x| y * width + x))
+            .filter(|&start| matches(start))
+            .map(|start| P::tryfrom_usize(start).unwrap())
+            .collect()
+    }
+
+    /// Return the top-left positions where any of the 8 symmetries
+    /// of the provided square pattern match the grid, along with
+    /// the [`Symmetry`] applied to the pattern
+    ///
+    /// Symmetries that generate the same pattern as a previous one
+    /// in [`Symmetry::ALL`] are skipped, so that each match is
+    /// reported only once. See
+    /// [`Grid::find_pattern`].
+    pub fn find_pattern_symmetries<const PMAX: u16, const PSIZE: usize>(
+        &self,
+        pattern: &Grid<T, Pos<PMAX, PMAX>, PSIZE>,
+        wildcard: Option<&T>,
+    ) -> Vec<(Symmetry, P)>
+    where
+        T: Clone + PartialEq,
+    {
+        let mut variants = Vec::with_capacity(8);
+        let mut matches = vec![];
+        for symmetry in Symmetry::ALL {
+            let variant = symmetry.apply(pattern);
+            if !variants.contains(&variant) {
+                let found = self.find_pattern(&variant, wildcard);
+                matches.extend(found.into_iter().map(|pos| (symmetry, pos)));
+                variants.push(variant);
+            }
+        }
+        matches
+    }
+}
+
+/// Convert a coordinate to `usize`
+pub(crate) fn coord<C: TryInto<usize>>(c: C) -> usize {
+    let Ok(c) = c.try_into() else { panic!() };
+    c
+}
+
+/// Rotate `len` items by `n` positions towards the end, wrapping
+/// around, using only the provided `swap` of two indexes
+///
+/// Rotates by reversing all items and then each one of the two parts.
+pub(crate) fn rotate_by_swaps(len: usize, n: isize, mut swap: impl FnMut(usize, usize)) {
+    if len == 0 {
+        return;
+    }
+    let k = n.rem_euclid(len as isize) as usize;
+    let mut reverse = |start: usize, end: usize| {
+        for i in 0..(end - start) / 2 {
+            swap(start + i, end - 1 - i);
+        }
+    };
+    reverse(0, len);
+    reverse(0, k);
+    reverse(k, len);
+}
+
+/// Slide the movable items of `cells` towards the start of `lane`,
+/// which yields the indexes of the cells in order
+fn slide_lane<T>(
+    cells: &mut [T],
+    lane: impl Iterator<Item = usize> + Clone,
+    is_movable: impl Fn(&T) -> bool,
+    is_blocking: impl Fn(&T) -> bool,
+) -> usize {
+    let mut moved = 0;
+    // The first free position of the lane is the next one in `free`:
+    let mut free = lane.clone();
+    let mut lane = lane;
+    while let Some(index) = lane.next() {
+        if is_blocking(&cells[index]) {
+            free = lane.clone();
+        } else if is_movable(&cells[index]) {
+            let target = free.next().unwrap_or(index);
+            if target != index {
+                cells.swap(target, index);
+                moved += 1;
+            }
+        }
+    }
+    moved
 }
 
 // Rotations are only available for "square" grids
@@ -233,6 +610,31 @@ impl<T, const W: u16, const SIZE: usize> Grid<T, Pos<W, W>, SIZE> {
             }
         }
     }
+
+    /// Exchange the rows and columns in place
+    pub fn transpose_in_place(&mut self) {
+        let width = Pos::<W, W>::width();
+        for y in 0..width {
+            for x in y + 1..width {
+                self.0.swap(y * width + x, x * width + y);
+            }
+        }
+    }
+}
+
+// Transposition is available for all grids, exchanging the
+// dimensions of non-square ones
+impl<T: Clone, const W: u16, const H: u16, const SIZE: usize> Grid<T, Pos<W, H>, SIZE> {
+    /// Return a grid with the rows and columns exchanged
+    pub fn transpose(&self) -> Grid<T, Pos<H, W>, SIZE> {
+        Grid(
+            core::array::from_fn(|i| {
+                let pos = Pos::<H, W>::tryfrom_usize(i).unwrap().transpose();
+                self[pos].clone()
+            }),
+            core::marker::PhantomData,
+        )
+    }
 }
 
 // Default
@@ -240,8 +642,8 @@ impl<T, const W: u16, const SIZE: usize> Grid<T, Pos<W, W>, SIZE> {
 impl<T: Default, P: PosT, const SIZE: usize> Default for Grid<T, P, SIZE> {
     fn default() -> Self {
         Self(
-            std::array::from_fn(|_| (T::default())),
-            std::marker::PhantomData,
+            core::array::from_fn(|_| T::default()),
+            core::marker::PhantomData,
         )
     }
 }
@@ -268,7 +670,7 @@ impl<T: Default, P: PosT, const SIZE: usize> TryFrom<Vec<Vec<T>>> for Grid<T, P,
             return Err(Error::OutOfBounds);
         }
         Ok(Self(
-            std::array::from_fn(|i| {
+            core::array::from_fn(|i| {
                 let pos = P::tryfrom_usize(i).unwrap();
                 let Ok(x) = pos.x().try_into() else {
                     return T::default();
@@ -277,12 +679,12 @@ impl<T: Default, P: PosT, const SIZE: usize> TryFrom<Vec<Vec<T>>> for Grid<T, P,
                     return T::default();
                 };
                 if y < vec.len() && x < vec[y].len() {
-                    std::mem::take(&mut vec[y][x])
+                    core::mem::take(&mut vec[y][x])
                 } else {
                     T::default()
                 }
             }),
-            std::marker::PhantomData,
+            core::marker::PhantomData,
         ))
     }
 }
@@ -339,7 +741,7 @@ impl<T, P: PosT, const SIZE: usize> convert::AsMut<[T; SIZE]> for Grid<T, P, SIZ
 
 impl<'a, T, P: PosT, const SIZE: usize> IntoIterator for &'a Grid<T, P, SIZE> {
     type Item = &'a T;
-    type IntoIter = std::slice::Iter<'a, T>;
+    type IntoIter = core::slice::Iter<'a, T>;
     #[inline]
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
@@ -348,7 +750,7 @@ impl<'a, T, P: PosT, const SIZE: usize> IntoIterator for &'a Grid<T, P, SIZE> {
 
 impl<'a, T, P: PosT, const SIZE: usize> IntoIterator for &'a mut Grid<T, P, SIZE> {
     type Item = &'a mut T;
-    type IntoIter = std::slice::IterMut<'a, T>;
+    type IntoIter = core::slice::IterMut<'a, T>;
     #[inline]
     fn into_iter(self) -> Self::IntoIter {
         self.iter_mut()
@@ -357,7 +759,7 @@ impl<'a, T, P: PosT, const SIZE: usize> IntoIterator for &'a mut Grid<T, P, SIZE
 
 impl<T, P: PosT, const SIZE: usize> IntoIterator for Grid<T, P, SIZE> {
     type Item = T;
-    type IntoIter = std::array::IntoIter<T, SIZE>;
+    type IntoIter = core::array::IntoIter<T, SIZE>;
     #[inline]
     fn into_iter(self) -> Self::IntoIter {
         IntoIterator::into_iter(self.0)
@@ -518,7 +920,7 @@ pub fn display_fmt_helper(
 
 /// Pretty-printer [`Grid`] display implementation
 ///
-/// The [`Display`](std::fmt::Display) implementation of grid was made
+/// The [`Display`](core::fmt::Display) implementation of grid was made
 /// to print an ascii-like grid.
 /// It does that in one pass, and uses the padding parameter as the
 /// size to reserve for each member.
@@ -10,11 +10,16 @@
 //! This submodule has the [`Gridbool`] type and the associated
 //! functionality.
 
-use std::fmt;
-use std::iter;
-use std::ops;
+use alloc::boxed::Box;
+use alloc::string::ToString;
+use alloc::vec;
+use core::fmt;
+use core::iter;
+use core::ops;
 
 use super::grid;
+use super::grid::coord;
+use super::grid::rotate_by_swaps;
 use super::pos::Pos;
 use super::postrait::PosT;
 
@@ -39,7 +44,7 @@ macro_rules! impl_assert {
 macro_rules! gridbool_create {
     ($sqrid: ty) => {
         $crate::Gridbool<$crate::pos_create!($sqrid),
-        { (((<$sqrid>::XMAX as usize + 1) * (<$sqrid>::YMAX as usize + 1) + 31) / 32) }>
+        { ((<$sqrid>::XMAX as usize + 1) * (<$sqrid>::YMAX as usize + 1)).div_ceil(32) }>
     };
 }
 
@@ -54,8 +59,15 @@ macro_rules! gridbool_create {
 ///
 /// We can use the [`gridbool_create`] macro to use a [`Pos`] as a
 /// source of these values.
+///
+/// Lines and columns can be read with [`Gridbool::line`] and
+/// [`Gridbool::column`], like in [`Grid`](crate::Grid). As the values
+/// are packed in bits, there are no mut references to them; lines and
+/// columns are written with [`Gridbool::set_line`] and
+/// [`Gridbool::set_column`] instead.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
-pub struct Gridbool<P: PosT, const WORDS: usize>([u32; WORDS], std::marker::PhantomData<P>);
+#[repr(transparent)]
+pub struct Gridbool<P: PosT, const WORDS: usize>([u32; WORDS], core::marker::PhantomData<P>);
 
 impl<P: PosT, const WORDS: usize> Gridbool<P, WORDS> {
     // Create the _ASSERTS constant to check W * H == SIZE
@@ -87,7 +99,25 @@ impl<P: PosT, const WORDS: usize> Gridbool<P, WORDS> {
         } else {
             Self::WORD_FALSE
         };
-        Gridbool([v; WORDS], std::marker::PhantomData)
+        Gridbool([v; WORDS], core::marker::PhantomData)
+    }
+
+    /// Create a heap-allocated Gridbool filled with the provided
+    /// `value`, without building it on the stack
+    pub fn new_boxed(value: bool) -> Box<Self> {
+        let _ = Self::_ASSERTS;
+        let v = if value {
+            Self::WORD_TRUE
+        } else {
+            Self::WORD_FALSE
+        };
+        let array: Box<[u32; WORDS]> = match vec![v; WORDS].into_boxed_slice().try_into() {
+            Ok(array) => array,
+            Err(_) => unreachable!(),
+        };
+        // SAFETY: Gridbool is repr(transparent) over the array, as
+        // PhantomData is zero-sized.
+        unsafe { Box::from_raw(Box::into_raw(array) as *mut Self) }
     }
 
     #[inline]
@@ -97,6 +127,33 @@ impl<P: PosT, const WORDS: usize> Gridbool<P, WORDS> {
         (byte, bit)
     }
 
+    #[inline]
+    fn get_index(&self, i: usize) -> bool {
+        let (byte, bit) = Self::byte_bit(i);
+        self.0[byte] & bit != 0
+    }
+
+    #[inline]
+    fn set_index(&mut self, i: usize, value: bool) {
+        let (byte, bit) = Self::byte_bit(i);
+        if value {
+            self.0[byte] |= bit;
+        } else {
+            self.0[byte] &= !bit;
+        }
+    }
+
+    #[inline]
+    fn swap_index(&mut self, i: usize, j: usize) {
+        let (vi, vj) = (self.get_index(i), self.get_index(j));
+        if vi != vj {
+            let (byte, bit) = Self::byte_bit(i);
+            self.0[byte] ^= bit;
+            let (byte, bit) = Self::byte_bit(j);
+            self.0[byte] ^= bit;
+        }
+    }
+
     /// Set the provided [`Pos`] position to `true`.
     #[inline]
     pub fn set_t(&mut self, posref: &P) {
@@ -214,6 +271,103 @@ impl<P: PosT, const WORDS: usize> Gridbool<P, WORDS> {
             }
         }
     }
+
+    /// Iterate over the values of a specific line
+    pub fn line(&self, y: P::Ytype) -> impl iter::Iterator<Item = bool> + '_ {
+        let start = coord(y) * P::width();
+        (start..start + P::width()).map(|i| self.get_index(i))
+    }
+
+    /// Set the values of a specific line from an iterator
+    ///
+    /// This is the equivalent of [`Grid::line_mut`](crate::Grid::line_mut);
+    /// values past the end of the line are ignored, and a shorter
+    /// iterator leaves the rest of the line unchanged.
+    pub fn set_line(&mut self, y: P::Ytype, values: impl IntoIterator<Item = bool>) {
+        let start = coord(y) * P::width();
+        for (i, value) in (start..start + P::width()).zip(values) {
+            self.set_index(i, value);
+        }
+    }
+
+    /// Iterate over the rows, each one as an iterator over its values
+    pub fn rows(&self) -> impl iter::Iterator<Item = impl iter::Iterator<Item = bool> + '_> + '_ {
+        (0..P::height()).map(|y| {
+            let start = y * P::width();
+            (start..start + P::width()).map(|i| self.get_index(i))
+        })
+    }
+
+    /// Iterate over the values of a specific column
+    pub fn column(&self, x: P::Xtype) -> impl iter::Iterator<Item = bool> + '_ {
+        (coord(x)..P::width() * P::height())
+            .step_by(P::width())
+            .map(|i| self.get_index(i))
+    }
+
+    /// Set the values of a specific column from an iterator
+    ///
+    /// This is the equivalent of
+    /// [`Grid::column_mut`](crate::Grid::column_mut); values past the
+    /// end of the column are ignored, and a shorter iterator leaves
+    /// the rest of the column unchanged.
+    pub fn set_column(&mut self, x: P::Xtype, values: impl IntoIterator<Item = bool>) {
+        let indexes = (coord(x)..P::width() * P::height()).step_by(P::width());
+        for (i, value) in indexes.zip(values) {
+            self.set_index(i, value);
+        }
+    }
+
+    /// Iterate over the columns, each one as an iterator over its
+    /// values
+    pub fn columns(
+        &self,
+    ) -> impl iter::Iterator<Item = impl iter::Iterator<Item = bool> + '_> + '_ {
+        (0..P::width()).map(|x| {
+            (x..P::width() * P::height())
+                .step_by(P::width())
+                .map(|i| self.get_index(i))
+        })
+    }
+
+    /// Swap the values of two rows
+    pub fn swap_rows(&mut self, y1: P::Ytype, y2: P::Ytype) {
+        let width = P::width();
+        let (y1, y2) = (coord(y1), coord(y2));
+        for x in 0..width {
+            self.swap_index(y1 * width + x, y2 * width + x);
+        }
+    }
+
+    /// Swap the values of two columns
+    pub fn swap_columns(&mut self, x1: P::Xtype, x2: P::Xtype) {
+        let width = P::width();
+        let (x1, x2) = (coord(x1), coord(x2));
+        for y in 0..P::height() {
+            self.swap_index(y * width + x1, y * width + x2);
+        }
+    }
+
+    /// Rotate the values of a row by `n` positions, wrapping around
+    ///
+    /// Positive values of `n` move the values east, negative ones
+    /// west.
+    pub fn rotate_row(&mut self, y: P::Ytype, n: isize) {
+        let start = coord(y) * P::width();
+        rotate_by_swaps(P::width(), n, |i, j| self.swap_index(start + i, start + j));
+    }
+
+    /// Rotate the values of a column by `n` positions, wrapping around
+    ///
+    /// Positive values of `n` move the values south, negative ones
+    /// north.
+    pub fn rotate_column(&mut self, x: P::Xtype, n: isize) {
+        let width = P::width();
+        let x = coord(x);
+        rotate_by_swaps(P::height(), n, |i, j| {
+            self.swap_index(i * width + x, j * width + x)
+        });
+    }
 }
 
 // Rotations are only available for "square" gridbools
@@ -260,6 +414,27 @@ impl<const XYMAX: u16, const WORDS: usize> Gridbool<Pos<XYMAX, XYMAX>, WORDS> {
             }
         }
     }
+
+    /// Exchange the rows and columns in place
+    pub fn transpose_in_place(&mut self) {
+        let width = Pos::<XYMAX, XYMAX>::width();
+        for y in 0..width {
+            for x in y + 1..width {
+                self.swap_index(y * width + x, x * width + y);
+            }
+        }
+    }
+}
+
+// Transposition is available for all gridbools, exchanging the
+// dimensions of non-square ones
+impl<const W: u16, const H: u16, const WORDS: usize> Gridbool<Pos<W, H>, WORDS> {
+    /// Return a gridbool with the rows and columns exchanged
+    pub fn transpose(&self) -> Gridbool<Pos<H, W>, WORDS> {
+        let mut gridbool = Gridbool::<Pos<H, W>, WORDS>::ALL_FALSE;
+        gridbool.set_iter_t(self.iter_t().map(|pos| pos.transpose()));
+        gridbool
+    }
 }
 
 impl<P: PosT, const WORDS: usize> Default for Gridbool<P, WORDS> {
@@ -0,0 +1,243 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+//! Unbounded grid, stored in chunks allocated on demand
+//!
+//! [`InfiniteGrid`] is indexed by signed `(i32, i32)` coordinates. Its values are stored in
+//! fixed-size [`Grid`] chunks that are only allocated when a value is changed; all other
+//! positions have the default value provided on creation. The grid also keeps track of the
+//! bounding box of the positions that were changed.
+//!
+//! [`InfiniteGrid::set`] doesn't count writes of the default value to positions without a
+//! chunk as changes, while mutable access with [`InfiniteGrid::get_mut`] or `IndexMut`
+//! always counts as one.
+//!
+//! `(i32, i32)` implements [`PosT`](crate::postrait::PosT), and [`InfiniteGrid`] implements [`MapPos`] and
+//! [`SetPos`] (for `bool`), so it can be used by the search algorithms - as long as the `go`
+//! function limits the region, as the grid itself doesn't.
+//!
+//! Example usage:
+//!
+//! ```
+//! use sqrid::InfiniteGrid;
+//!
+//! let mut grid = InfiniteGrid::new('.');
+//! grid[(-1000, 5)] = '#';
+//! grid[(2000, -7)] = '#';
+//! assert_eq!(grid[(0, 0)], '.');
+//! assert_eq!(grid.bounding_box(), Some(((-1000, -7), (2000, 5))));
+//! assert_eq!(grid.chunks_len(), 2);
+//! ```
+
+use core::ops;
+
+use super::grid::Grid;
+use super::mappos::MapPos;
+use super::pos::Pos;
+use super::setpos::SetPos;
+
+/// Width and height of each chunk
+const CHUNK: i32 = 16;
+
+/// Position inside a chunk
+type ChunkPos = Pos<{ CHUNK as u16 - 1 }, { CHUNK as u16 - 1 }>;
+
+/// Chunk of an [`InfiniteGrid`]
+type Chunk<T> = Grid<T, ChunkPos, { (CHUNK * CHUNK) as usize }>;
+
+/// Map from the chunk keys to the chunks; a
+/// [`BTreeMap`](alloc::collections::BTreeMap) is used without the
+/// `std` feature
+#[cfg(feature = "std")]
+type ChunkMap<T> = std::collections::HashMap<(i32, i32), Chunk<T>>;
+#[cfg(not(feature = "std"))]
+type ChunkMap<T> = alloc::collections::BTreeMap<(i32, i32), Chunk<T>>;
+
+/// Split the coordinates in the key of the chunk and the position
+/// inside it
+#[inline]
+fn split(pos: (i32, i32)) -> ((i32, i32), ChunkPos) {
+    let key = (pos.0.div_euclid(CHUNK), pos.1.div_euclid(CHUNK));
+    let inner = ChunkPos::new_unwrap(
+        pos.0.rem_euclid(CHUNK) as u16,
+        pos.1.rem_euclid(CHUNK) as u16,
+    );
+    (key, inner)
+}
+
+/// A grid without bounds, indexed by `(i32, i32)` coordinates
+#[derive(Debug, Clone)]
+pub struct InfiniteGrid<T> {
+    default: T,
+    chunks: ChunkMap<T>,
+    bbox: Option<((i32, i32), (i32, i32))>,
+}
+
+impl<T> InfiniteGrid<T> {
+    /// Create an empty grid, where all positions have the provided
+    /// `default` value
+    pub fn new(default: T) -> Self {
+        InfiniteGrid {
+            default,
+            chunks: ChunkMap::new(),
+            bbox: None,
+        }
+    }
+
+    /// Return the value of the positions that were never changed
+    #[inline]
+    pub fn default_value(&self) -> &T {
+        &self.default
+    }
+
+    /// Get a reference to the value at the provided position
+    #[inline]
+    pub fn get(&self, pos: &(i32, i32)) -> &T {
+        let (key, inner) = split(*pos);
+        self.chunks
+            .get(&key)
+            .map(|chunk| &chunk[inner])
+            .unwrap_or(&self.default)
+    }
+
+    /// Get a mutable reference to the value at the provided
+    /// position, allocating its chunk if necessary
+    ///
+    /// This always counts as a change, even if the value is not
+    /// modified: the position is included in the bounding box. The
+    /// same goes for `IndexMut`; use [`InfiniteGrid::set`] to avoid
+    /// that when writing the default value.
+    pub fn get_mut(&mut self, pos: &(i32, i32)) -> &mut T
+    where
+        T: Copy,
+    {
+        let (x, y) = *pos;
+        self.bbox = Some(match self.bbox {
+            None => (*pos, *pos),
+            Some(((xmin, ymin), (xmax, ymax))) => {
+                ((xmin.min(x), ymin.min(y)), (xmax.max(x), ymax.max(y)))
+            }
+        });
+        let (key, inner) = split(*pos);
+        let default = self.default;
+        &mut self
+            .chunks
+            .entry(key)
+            .or_insert_with(|| Chunk::repeat(default))[inner]
+    }
+
+    /// Set the value at the provided position
+    ///
+    /// Setting the default value doesn't allocate a chunk nor grow
+    /// the bounding box.
+    #[inline]
+    pub fn set(&mut self, pos: (i32, i32), value: T)
+    where
+        T: Copy + PartialEq,
+    {
+        if value != self.default {
+            *self.get_mut(&pos) = value;
+        } else {
+            let (key, inner) = split(pos);
+            if let Some(chunk) = self.chunks.get_mut(&key) {
+                chunk[inner] = value;
+            }
+        }
+    }
+
+    /// Return the top-left and bottom-right corners of the rectangle
+    /// that contains all the positions that were changed, or `None`
+    /// if the grid is untouched
+    ///
+    /// The bounding box is not shrunk when positions are set back to
+    /// the default value.
+    #[inline]
+    pub fn bounding_box(&self) -> Option<((i32, i32), (i32, i32))> {
+        self.bbox
+    }
+
+    /// Return the number of chunks allocated
+    #[inline]
+    pub fn chunks_len(&self) -> usize {
+        self.chunks.len()
+    }
+
+    /// Iterate over the positions in the bounding box and their
+    /// values, line by line
+    pub fn iter_pos(&self) -> impl Iterator<Item = ((i32, i32), &T)> + '_ {
+        let ((xmin, ymin), (xmax, ymax)) = self.bbox.unwrap_or(((0, 0), (-1, -1)));
+        (ymin..=ymax)
+            .flat_map(move |y| (xmin..=xmax).map(move |x| (x, y)))
+            .map(|pos| (pos, self.get(&pos)))
+    }
+}
+
+impl<T: Default> Default for InfiniteGrid<T> {
+    fn default() -> Self {
+        InfiniteGrid::new(T::default())
+    }
+}
+
+impl<T> ops::Index<(i32, i32)> for InfiniteGrid<T> {
+    type Output = T;
+    #[inline]
+    fn index(&self, pos: (i32, i32)) -> &Self::Output {
+        self.get(&pos)
+    }
+}
+
+impl<T> ops::Index<&(i32, i32)> for InfiniteGrid<T> {
+    type Output = T;
+    #[inline]
+    fn index(&self, pos: &(i32, i32)) -> &Self::Output {
+        self.get(pos)
+    }
+}
+
+impl<T: Copy> ops::IndexMut<(i32, i32)> for InfiniteGrid<T> {
+    #[inline]
+    fn index_mut(&mut self, pos: (i32, i32)) -> &mut T {
+        self.get_mut(&pos)
+    }
+}
+
+impl<T: Copy> ops::IndexMut<&(i32, i32)> for InfiniteGrid<T> {
+    #[inline]
+    fn index_mut(&mut self, pos: &(i32, i32)) -> &mut T {
+        self.get_mut(pos)
+    }
+}
+
+/* MapPos and SetPos */
+
+impl<Item, const WORDS: usize, const SIZE: usize> MapPos<Item, (i32, i32), WORDS, SIZE>
+    for InfiniteGrid<Item>
+where
+    Item: Copy + PartialEq,
+{
+    fn new(item: Item) -> Self {
+        InfiniteGrid::new(item)
+    }
+    fn get(&self, pos: &(i32, i32)) -> &Item {
+        self.get(pos)
+    }
+    fn set(&mut self, pos: (i32, i32), item: Item) {
+        self.set(pos, item);
+    }
+}
+
+impl<const WORDS: usize, const SIZE: usize> SetPos<(i32, i32), WORDS, SIZE> for InfiniteGrid<bool> {
+    fn contains(&self, pos: &(i32, i32)) -> bool {
+        *self.get(pos)
+    }
+    fn insert(&mut self, pos: (i32, i32)) {
+        self.set(pos, true);
+    }
+    fn remove(&mut self, pos: &(i32, i32)) {
+        self.set(*pos, false);
+    }
+}
@@ -0,0 +1,155 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+//! Search limits, budgets and early termination
+//!
+//! The searches in [`bf`](crate::bf), [`astar`](crate::astar) and [`ucs`](crate::ucs) run
+//! until the destination is found or until there are no more positions to visit. The
+//! `search_*_limits` variants of these searches also take a [`SearchLimits`] that can stop
+//! them earlier, returning [`LimitsError::BudgetExceeded`] along with the partial "came
+//! from" [`MapPos`](super::MapPos) of the positions visited so far.
+//!
+//! [`LimitsError`] converts into [`Error`], with `BudgetExceeded` becoming
+//! [`Error::BudgetExceeded`], so callers that don't need the partial map can use `?` in
+//! functions that return `Result<_, sqrid::Error>`.
+//!
+//! The limits are checked before each position is expanded, and the origin counts as an
+//! expanded position in all searches. The breadth-first search expands a whole front at
+//! a time, so it only expands the next front if all its positions fit in `max_expanded`.
+//!
+//! Example usage:
+//!
+//! ```
+//! type Sqrid = sqrid::sqrid_create!(9, 9, false);
+//! type Pos = sqrid::pos_create!(Sqrid);
+//!
+//! let limits = sqrid::SearchLimits {
+//!     max_cost: Some(5),
+//!     ..Default::default()
+//! };
+//! let result = Sqrid::bfs_path_limits(sqrid::pos_dir_add_ok, &Pos::TOP_LEFT,
+//!                                     |pos| pos == Pos::BOTTOM_RIGHT, &limits);
+//! assert!(matches!(result, Err(sqrid::LimitsError::BudgetExceeded(_))));
+//! let error = sqrid::Error::from(result.unwrap_err());
+//! assert_eq!(error, sqrid::Error::BudgetExceeded);
+//! ```
+
+use alloc::boxed::Box;
+use core::error;
+use core::fmt;
+
+use super::ucs::Cost;
+use super::Dir;
+use super::Error;
+use super::Grid;
+
+/// Limits that stop a search before it finishes
+///
+/// All limits are optional, and the [`Default`] instance has none of
+/// them.
+#[derive(Default, Clone, Copy)]
+pub struct SearchLimits<'a> {
+    /// Maximum cost of the positions visited; for breadth-first
+    /// searches this is the distance from the origin.
+    pub max_cost: Option<Cost>,
+    /// Maximum number of positions expanded, including the origin.
+    pub max_expanded: Option<usize>,
+    /// Cancellation callback, called before expanding each position;
+    /// the search stops if it returns `true`.
+    pub cancel: Option<&'a dyn Fn() -> bool>,
+}
+
+impl SearchLimits<'_> {
+    /// Return true if a position with the provided `cost` is beyond
+    /// the maximum cost.
+    pub fn cost_exceeded(&self, cost: Cost) -> bool {
+        self.max_cost.is_some_and(|max| cost > max)
+    }
+
+    /// Return true if a search that has already expanded `expanded`
+    /// positions should stop instead of expanding `count` more.
+    ///
+    /// Calls the cancellation callback.
+    pub fn expansion_exceeded(&self, expanded: usize, count: usize) -> bool {
+        self.max_expanded
+            .is_some_and(|max| expanded.saturating_add(count) > max)
+            || self.cancel.is_some_and(|cancel| cancel())
+    }
+}
+
+impl fmt::Debug for SearchLimits<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SearchLimits")
+            .field("max_cost", &self.max_cost)
+            .field("max_expanded", &self.max_expanded)
+            .field("cancel", &self.cancel.map(|_| "Fn"))
+            .finish()
+    }
+}
+
+/* LimitsError ****************************************************************/
+
+/// Errors returned by the searches that can be stopped by
+/// [`SearchLimits`]
+///
+/// `MapPosDir` is the "came from" [`MapPos`](super::MapPos) used by
+/// the search. Converts into [`Error`], dropping the map.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LimitsError<MapPosDir> {
+    /// The search stopped because of its [`SearchLimits`]
+    ///
+    /// Carries the "came from" directions of the positions visited
+    /// so far, which can be passed to
+    /// [`camefrom_into_path`](super::camefrom_into_path).
+    BudgetExceeded(MapPosDir),
+    /// The search failed with a regular [`Error`]
+    Error(Error),
+}
+
+/// The [`LimitsError`] returned by the `Sqrid::*_path_limits` functions,
+/// which use a boxed [`Grid`] as the "came from" map
+pub type LimitsErrorGrid<P, const SIZE: usize> = LimitsError<Box<Grid<Option<Dir>, P, SIZE>>>;
+
+impl<MapPosDir> LimitsError<MapPosDir> {
+    /// Convert the "came from" map of [`LimitsError::BudgetExceeded`]
+    /// with the provided function
+    pub fn map<M, F>(self, f: F) -> LimitsError<M>
+    where
+        F: FnOnce(MapPosDir) -> M,
+    {
+        match self {
+            LimitsError::BudgetExceeded(m) => LimitsError::BudgetExceeded(f(m)),
+            LimitsError::Error(e) => LimitsError::Error(e),
+        }
+    }
+}
+
+impl<MapPosDir> From<Error> for LimitsError<MapPosDir> {
+    fn from(e: Error) -> Self {
+        LimitsError::Error(e)
+    }
+}
+
+impl<MapPosDir> From<LimitsError<MapPosDir>> for Error {
+    fn from(e: LimitsError<MapPosDir>) -> Self {
+        match e {
+            LimitsError::BudgetExceeded(_) => Error::BudgetExceeded,
+            LimitsError::Error(e) => e,
+        }
+    }
+}
+
+impl<MapPosDir: fmt::Debug> error::Error for LimitsError<MapPosDir> {}
+
+impl<MapPosDir> fmt::Display for LimitsError<MapPosDir> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LimitsError::BudgetExceeded(_) => Error::BudgetExceeded.fmt(f),
+            LimitsError::Error(e) => e.fmt(f),
+        }
+    }
+}
@@ -8,14 +8,20 @@
 //! Module that abstracts maps with [`super::pos::Pos`] indexes
 //!
 //! The [`MapPos`] trait is used to parameterize the search algorithms,
-//! allowing us to use [`Grid`], [`std::collections::HashMap`] or
-//! [`std::collections::BTreeMap`] for the internal algorithm
-//! structures.
+//! allowing us to use [`Grid`] or [`alloc::collections::BTreeMap`] for
+//! the internal algorithm structures.
+#![cfg_attr(
+    feature = "std",
+    doc = "[`std::collections::HashMap`] can also be used with the `std` feature."
+)]
 //!
 //! Note: Using [`Grid`] is not always feasible depending on the
-//! dimensions of the grid.
+//! dimensions of the grid, as it lives on the stack; `Box<Grid>` can
+//! be used instead for large grids.
 
-use std::collections;
+use alloc::boxed::Box;
+use alloc::collections;
+use alloc::vec::Vec;
 
 use super::dir::Dir;
 use super::error::Error;
@@ -54,9 +60,26 @@ where
 }
 
 impl<Item, P: PosT, const WORDS: usize, const SIZE: usize> MapPos<Item, P, WORDS, SIZE>
-    for (collections::HashMap<P, Item>, Item)
+    for Box<Grid<Item, P, SIZE>>
 where
-    P: Eq + std::hash::Hash,
+    Item: Copy,
+{
+    fn new(item: Item) -> Self {
+        Grid::<Item, P, SIZE>::new_boxed(item)
+    }
+    fn get(&self, pos: &P) -> &Item {
+        (**self).get(pos)
+    }
+    fn set(&mut self, pos: P, item: Item) {
+        self[pos] = item;
+    }
+}
+
+#[cfg(feature = "std")]
+impl<Item, P: PosT, const WORDS: usize, const SIZE: usize> MapPos<Item, P, WORDS, SIZE>
+    for (std::collections::HashMap<P, Item>, Item)
+where
+    P: Eq + core::hash::Hash,
 {
     fn new(item: Item) -> Self {
         (Default::default(), item)
@@ -102,7 +125,7 @@ where
     P: PosT,
     P: Copy,
     P: PartialEq,
-    P: std::ops::Add<Dir, Output = Result<P, Error>>,
+    P: core::ops::Add<Dir, Output = Result<P, Error>>,
     MapPosDir: MapPos<Option<Dir>, P, WORDS, SIZE>,
 {
     let distance = orig.manhattan(dest);
@@ -142,7 +165,7 @@ impl<const XMAX: u16, const YMAX: u16, const D: bool, const WORDS: usize, const
         P: PosT,
         P: Copy,
         P: PartialEq,
-        P: std::ops::Add<Dir, Output = Result<P, Error>>,
+        P: core::ops::Add<Dir, Output = Result<P, Error>>,
         MapPosDir: MapPos<Option<Dir>, P, WORDS, SIZE>,
     {
         super::camefrom_into_path(map, orig, dest)
@@ -0,0 +1,504 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+//! Maze generation
+//!
+//! This module generates perfect mazes - mazes where there is exactly one path between any
+//! two positions - using some classic algorithms:
+//! - [`backtracker`]: recursive backtracker, a randomized depth-first search; generates long
+//!   corridors with few branches.
+//! - [`prim`]: randomized Prim's algorithm; generates many short dead ends.
+//! - [`kruskal`]: randomized Kruskal's algorithm, using a union-find.
+//! - [`wilson`]: Wilson's algorithm, using loop-erased random walks; generates an unbiased
+//!   sample of all possible mazes.
+//! - [`eller`]: Eller's algorithm, which generates the maze one line at a time.
+//!
+//! The generated maze is a [`Grid`] of [`Openings`], the set of directions that are open
+//! in each position. [`braid`] can then remove the dead ends, creating loops; [`walls`]
+//! converts the maze into a [`Gridbool`] of walls, with each position of the maze in the odd
+//! coordinates; and [`go`] creates a movement function that can be used directly with the
+//! searches, like [`Sqrid::bfs_path`].
+//!
+//! The crate has no dependencies, so the random numbers are provided by the user: all
+//! generators take a closure that is called with `n` and must return a random number in the
+//! range `0..n`.
+//!
+//! Example usage:
+//!
+//! ```
+//! type Sqrid = sqrid::sqrid_create!(7, 7, false);
+//! type Pos = sqrid::pos_create!(Sqrid);
+//! type Maze = sqrid::grid_create!(Sqrid, sqrid::maze::Openings);
+//!
+//! // Simple linear congruential generator:
+//! let mut seed = 42_u64;
+//! let mut rng = |n: usize| {
+//!     seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+//!     (seed >> 33) as usize % n
+//! };
+//!
+//! let maze: Maze = Sqrid::maze(sqrid::maze::Algorithm::Wilson, &mut rng);
+//! let path = Sqrid::bfs_path(sqrid::maze::go(&maze), &Pos::TOP_LEFT,
+//!                            |pos| pos == Pos::BOTTOM_RIGHT)?;
+//! # Ok::<(), sqrid::Error>(())
+//! ```
+
+use alloc::collections::BTreeMap;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+
+use super::postrait::PosT;
+use super::Dir;
+use super::Error;
+use super::Grid;
+use super::Gridbool;
+use super::Sqrid;
+
+/* Openings *******************************************************************/
+
+/// Set of open directions of a maze position
+///
+/// Implemented as a bitmap indexed by [`Dir`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Openings(u8);
+
+impl Openings {
+    /// No open directions: a position surrounded by walls
+    pub const NONE: Self = Openings(0);
+
+    /// Return true if the provided direction is open
+    #[inline]
+    pub const fn contains(&self, dir: Dir) -> bool {
+        self.0 & (1 << dir as u8) != 0
+    }
+
+    /// Open the provided direction
+    #[inline]
+    pub fn insert(&mut self, dir: Dir) {
+        self.0 |= 1 << dir as u8;
+    }
+
+    /// Close the provided direction
+    #[inline]
+    pub fn remove(&mut self, dir: Dir) {
+        self.0 &= !(1 << dir as u8);
+    }
+
+    /// Return the number of open directions
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    /// Return true if there are no open directions
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Return true if this is a dead end, with a single open direction
+    #[inline]
+    pub const fn is_dead_end(&self) -> bool {
+        self.len() == 1
+    }
+
+    /// Iterate over the open directions
+    pub fn iter(&self) -> impl Iterator<Item = Dir> + '_ {
+        Dir::ALL8.into_iter().filter(|dir| self.contains(*dir))
+    }
+}
+
+impl fmt::Display for Openings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Box-drawing character that connects the open directions:
+        const CHARS: [char; 16] = [
+            '·', '╵', '╶', '└', '╷', '│', '┌', '├', '╴', '┘', '─', '┴', '┐', '┤', '┬', '┼',
+        ];
+        let index = usize::from(self.contains(Dir::N))
+            | usize::from(self.contains(Dir::E)) << 1
+            | usize::from(self.contains(Dir::S)) << 2
+            | usize::from(self.contains(Dir::W)) << 3;
+        write!(f, "{}", CHARS[index])
+    }
+}
+
+/* Helpers ********************************************************************/
+
+/// Open the passage between `pos` and its neighbor in `dir`
+fn carve<P, const SIZE: usize>(maze: &mut Grid<Openings, P, SIZE>, pos: P, next: P, dir: Dir)
+where
+    P: PosT,
+{
+    maze[pos].insert(dir);
+    maze[next].insert(-dir);
+}
+
+/// Return the neighbors of `pos` that pass the provided filter
+fn neighbors<P>(pos: P, filter: impl Fn(&P) -> bool) -> impl Iterator<Item = (P, Dir)>
+where
+    P: PosT,
+    P: Copy,
+    P: core::ops::Add<Dir, Output = Result<P, Error>>,
+{
+    Dir::iter::<false>().filter_map(move |dir| {
+        let next = (pos + dir).ok()?;
+        filter(&next).then_some((next, dir))
+    })
+}
+
+/// Pick a random element of the provided iterator
+fn choose<T>(mut rng: impl FnMut(usize) -> usize, iter: impl Iterator<Item = T>) -> Option<T> {
+    let mut items = iter.collect::<Vec<_>>();
+    if items.is_empty() {
+        None
+    } else {
+        let i = rng(items.len());
+        Some(items.swap_remove(i))
+    }
+}
+
+/// Pick a random position
+fn random_pos<P: PosT>(mut rng: impl FnMut(usize) -> usize) -> P {
+    P::tryfrom_usize(rng(P::dimensions())).expect("rng returned a number out of range")
+}
+
+/* Generators *****************************************************************/
+
+/// Maze generation algorithms
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Algorithm {
+    /// See [`backtracker`]
+    Backtracker,
+    /// See [`prim`]
+    Prim,
+    /// See [`kruskal`]
+    Kruskal,
+    /// See [`wilson`]
+    Wilson,
+    /// See [`eller`]
+    Eller,
+}
+
+/// Generate a maze using the provided [`Algorithm`]
+pub fn generate<P, const SIZE: usize>(
+    algorithm: Algorithm,
+    rng: impl FnMut(usize) -> usize,
+) -> Grid<Openings, P, SIZE>
+where
+    P: PosT,
+    P: Copy,
+    P: core::ops::Add<Dir, Output = Result<P, Error>>,
+{
+    match algorithm {
+        Algorithm::Backtracker => backtracker(rng),
+        Algorithm::Prim => prim(rng),
+        Algorithm::Kruskal => kruskal(rng),
+        Algorithm::Wilson => wilson(rng),
+        Algorithm::Eller => eller(rng),
+    }
+}
+
+/// Generate a maze using the recursive backtracker algorithm
+///
+/// Starting at a random position, walks to random unvisited neighbors, backtracking when
+/// there are none left.
+pub fn backtracker<P, const SIZE: usize>(
+    mut rng: impl FnMut(usize) -> usize,
+) -> Grid<Openings, P, SIZE>
+where
+    P: PosT,
+    P: Copy,
+    P: core::ops::Add<Dir, Output = Result<P, Error>>,
+{
+    let mut maze = Grid::<Openings, P, SIZE>::repeat(Openings::NONE);
+    let mut visited = Grid::<bool, P, SIZE>::repeat(false);
+    let start = random_pos::<P>(&mut rng);
+    visited[start] = true;
+    let mut stack = vec![start];
+    while let Some(&pos) = stack.last() {
+        if let Some((next, dir)) = choose(&mut rng, neighbors(pos, |p| !visited[p])) {
+            carve(&mut maze, pos, next, dir);
+            visited[next] = true;
+            stack.push(next);
+        } else {
+            stack.pop();
+        }
+    }
+    maze
+}
+
+/// Generate a maze using the randomized Prim's algorithm
+///
+/// Grows the maze from a random position by opening random walls between the maze and the
+/// positions that are not part of it yet.
+pub fn prim<P, const SIZE: usize>(mut rng: impl FnMut(usize) -> usize) -> Grid<Openings, P, SIZE>
+where
+    P: PosT,
+    P: Copy,
+    P: core::ops::Add<Dir, Output = Result<P, Error>>,
+{
+    let mut maze = Grid::<Openings, P, SIZE>::repeat(Openings::NONE);
+    let mut visited = Grid::<bool, P, SIZE>::repeat(false);
+    let start = random_pos::<P>(&mut rng);
+    visited[start] = true;
+    let mut frontier = neighbors(start, |_| true)
+        .map(|(_, dir)| (start, dir))
+        .collect::<Vec<_>>();
+    while !frontier.is_empty() {
+        let (pos, dir) = frontier.swap_remove(rng(frontier.len()));
+        let Ok(next) = pos + dir else {
+            continue;
+        };
+        if visited[next] {
+            continue;
+        }
+        carve(&mut maze, pos, next, dir);
+        visited[next] = true;
+        frontier.extend(neighbors(next, |p| !visited[p]).map(|(_, dir)| (next, dir)));
+    }
+    maze
+}
+
+/// Generate a maze using the randomized Kruskal's algorithm
+///
+/// Opens the walls in a random order, skipping the ones that would connect positions that
+/// are already connected.
+pub fn kruskal<P, const SIZE: usize>(mut rng: impl FnMut(usize) -> usize) -> Grid<Openings, P, SIZE>
+where
+    P: PosT,
+    P: Copy,
+    P: core::ops::Add<Dir, Output = Result<P, Error>>,
+{
+    fn find(parent: &mut [usize], mut i: usize) -> usize {
+        while parent[i] != i {
+            parent[i] = parent[parent[i]];
+            i = parent[i];
+        }
+        i
+    }
+    let mut maze = Grid::<Openings, P, SIZE>::repeat(Openings::NONE);
+    let mut walls = P::iter()
+        .flat_map(|pos| [(pos, Dir::E), (pos, Dir::S)])
+        .filter(|&(pos, dir)| (pos + dir).is_ok())
+        .collect::<Vec<_>>();
+    // Fisher-Yates shuffle:
+    for i in (1..walls.len()).rev() {
+        walls.swap(i, rng(i + 1));
+    }
+    let mut parent = (0..SIZE).collect::<Vec<_>>();
+    for (pos, dir) in walls {
+        let Ok(next) = pos + dir else {
+            continue;
+        };
+        let root1 = find(&mut parent, pos.to_usize());
+        let root2 = find(&mut parent, next.to_usize());
+        if root1 != root2 {
+            parent[root2] = root1;
+            carve(&mut maze, pos, next, dir);
+        }
+    }
+    maze
+}
+
+/// Generate a maze using Wilson's algorithm
+///
+/// Adds each position to the maze with a random walk that ends when it reaches the maze,
+/// erasing the loops created on the way.
+pub fn wilson<P, const SIZE: usize>(mut rng: impl FnMut(usize) -> usize) -> Grid<Openings, P, SIZE>
+where
+    P: PosT,
+    P: Copy,
+    P: core::ops::Add<Dir, Output = Result<P, Error>>,
+{
+    let mut maze = Grid::<Openings, P, SIZE>::repeat(Openings::NONE);
+    let mut intree = Grid::<bool, P, SIZE>::repeat(false);
+    // Last direction taken from each position in the current walk;
+    // overwriting it is what erases the loops:
+    let mut walk = Grid::<Option<Dir>, P, SIZE>::repeat(None);
+    intree[random_pos::<P>(&mut rng)] = true;
+    for start in P::iter() {
+        let mut pos = start;
+        while !intree[pos] {
+            let (next, dir) = choose(&mut rng, neighbors(pos, |_| true))
+                .expect("maze position without neighbors");
+            walk[pos] = Some(dir);
+            pos = next;
+        }
+        let mut pos = start;
+        while !intree[pos] {
+            let dir = walk[pos].expect("walk direction not set");
+            let next = (pos + dir).expect("walk went out of bounds");
+            carve(&mut maze, pos, next, dir);
+            intree[pos] = true;
+            pos = next;
+        }
+    }
+    maze
+}
+
+/// Generate a maze using Eller's algorithm
+///
+/// Generates the maze line by line, keeping track of which positions of the current line are
+/// connected to each other by the previous lines.
+pub fn eller<P, const SIZE: usize>(mut rng: impl FnMut(usize) -> usize) -> Grid<Openings, P, SIZE>
+where
+    P: PosT,
+    P: Copy,
+    P: core::ops::Add<Dir, Output = Result<P, Error>>,
+{
+    let mut maze = Grid::<Openings, P, SIZE>::repeat(Openings::NONE);
+    let width = P::width();
+    let height = P::height();
+    let at = |x: usize, y: usize| P::tryfrom_usize(y * width + x).expect("position out of bounds");
+    // Set of each position of the current line; 0 means no set yet:
+    let mut sets = vec![0_usize; width];
+    let mut nextset = 1;
+    for y in 0..height {
+        let last = y + 1 == height;
+        for set in sets.iter_mut().filter(|set| **set == 0) {
+            *set = nextset;
+            nextset += 1;
+        }
+        // Join adjacent positions of different sets; in the last line,
+        // join all of them:
+        for x in 0..width - 1 {
+            if sets[x] != sets[x + 1] && (last || rng(2) == 0) {
+                carve(&mut maze, at(x, y), at(x + 1, y), Dir::E);
+                let (from, to) = (sets[x + 1], sets[x]);
+                for set in sets.iter_mut().filter(|set| **set == from) {
+                    *set = to;
+                }
+            }
+        }
+        if last {
+            break;
+        }
+        // Open at least one passage down from each set:
+        let mut members = BTreeMap::<usize, Vec<usize>>::new();
+        for (x, set) in sets.iter().enumerate() {
+            members.entry(*set).or_default().push(x);
+        }
+        let mut nextsets = vec![0_usize; width];
+        for (set, xs) in members {
+            let mut down = xs
+                .iter()
+                .copied()
+                .filter(|_| rng(2) == 0)
+                .collect::<Vec<_>>();
+            if down.is_empty() {
+                down.push(xs[rng(xs.len())]);
+            }
+            for x in down {
+                carve(&mut maze, at(x, y), at(x, y + 1), Dir::S);
+                nextsets[x] = set;
+            }
+        }
+        sets = nextsets;
+    }
+    maze
+}
+
+/* Post-processing ************************************************************/
+
+/// Remove the dead ends of the maze, by opening a wall of each one
+///
+/// Walls towards other dead ends are preferred, as that removes both of them at once. The
+/// result is a braid maze, with loops instead of dead ends.
+pub fn braid<P, const SIZE: usize>(
+    maze: &mut Grid<Openings, P, SIZE>,
+    mut rng: impl FnMut(usize) -> usize,
+) where
+    P: PosT,
+    P: Copy,
+    P: core::ops::Add<Dir, Output = Result<P, Error>>,
+{
+    for pos in P::iter() {
+        if !maze[pos].is_dead_end() {
+            continue;
+        }
+        let openings = maze[pos];
+        let (deadends, others): (Vec<_>, Vec<_>) = neighbors(pos, |_| true)
+            .filter(|(_, dir)| !openings.contains(*dir))
+            .partition(|(next, _)| maze[next].is_dead_end());
+        let choice = if deadends.is_empty() {
+            choose(&mut rng, others.into_iter())
+        } else {
+            choose(&mut rng, deadends.into_iter())
+        };
+        if let Some((next, dir)) = choice {
+            carve(maze, pos, next, dir);
+        }
+    }
+}
+
+/// Convert the maze into a [`Gridbool`] of walls
+///
+/// Each maze position `(x, y)` is placed in `(2x + 1, 2y + 1)` in the walls grid, with the
+/// positions between them set according to the passages. The walls grid must then be at least
+/// `2 * width + 1` by `2 * height + 1`; [`Error::OutOfBounds`] is returned otherwise. Positions
+/// outside of the maze are set as walls.
+pub fn walls<P, Q, const SIZE: usize, const WORDS: usize>(
+    maze: &Grid<Openings, P, SIZE>,
+) -> Result<Gridbool<Q, WORDS>, Error>
+where
+    P: PosT,
+    P: Copy,
+    Q: PosT,
+{
+    let width = P::width();
+    if Q::width() < 2 * width + 1 || Q::height() < 2 * P::height() + 1 {
+        return Err(Error::OutOfBounds);
+    }
+    let mut walls = Gridbool::<Q, WORDS>::ALL_TRUE;
+    for pos in P::iter() {
+        let i = pos.to_usize();
+        let (x, y) = (2 * (i % width) + 1, 2 * (i / width) + 1);
+        walls.set_f(&Q::new(x, y)?);
+        if maze[pos].contains(Dir::E) {
+            walls.set_f(&Q::new(x + 1, y)?);
+        }
+        if maze[pos].contains(Dir::S) {
+            walls.set_f(&Q::new(x, y + 1)?);
+        }
+    }
+    Ok(walls)
+}
+
+/// Create a movement function that follows the passages of the maze
+///
+/// The function can be used with the searches, like [`Sqrid::bfs_path`].
+pub fn go<P, const SIZE: usize>(maze: &Grid<Openings, P, SIZE>) -> impl Fn(P, Dir) -> Option<P> + '_
+where
+    P: PosT,
+    P: core::ops::Add<Dir, Output = Result<P, Error>>,
+{
+    move |pos, dir| {
+        if maze[&pos].contains(dir) {
+            (pos + dir).ok()
+        } else {
+            None
+        }
+    }
+}
+
+/* Sqrid plugin: **************************************************************/
+
+impl<const W: u16, const H: u16, const D: bool, const WORDS: usize, const SIZE: usize>
+    Sqrid<W, H, D, WORDS, SIZE>
+{
+    /// Generate a maze using the provided [`Algorithm`];
+    /// see [`maze`](crate::maze).
+    pub fn maze<P>(algorithm: Algorithm, rng: impl FnMut(usize) -> usize) -> Grid<Openings, P, SIZE>
+    where
+        P: PosT,
+        P: Copy,
+        P: core::ops::Add<Dir, Output = Result<P, Error>>,
+    {
+        generate::<P, SIZE>(algorithm, rng)
+    }
+}
@@ -10,6 +10,10 @@
 //! sqrid code is structure in a way that allows users to copy this
 //! directory to their projects and use sqrid as its own module,
 //! without a crate dependency.
+//!
+//! The module requires `extern crate alloc;` in the crate root, and
+//! the items that depend on `std`, such as the `HashMap`-based
+//! searches, are only available with a `std` feature.
 
 pub mod base;
 pub use self::base::*;
@@ -29,17 +33,48 @@ pub mod dir;
 pub use self::dir::*;
 pub mod posdir;
 pub use self::posdir::*;
+pub mod offset;
+pub use self::offset::*;
 
 pub mod grid;
 pub use self::grid::*;
 pub mod gridbool;
 pub use self::gridbool::*;
+pub mod infinitegrid;
+pub use self::infinitegrid::*;
 
 pub mod mappos;
 pub use self::mappos::*;
 pub mod setpos;
 pub use self::setpos::*;
 
+pub mod limits;
+pub use self::limits::*;
+pub mod stats;
+pub use self::stats::*;
+
+pub mod cycle;
+pub use self::cycle::*;
+pub mod symmetry;
+pub use self::symmetry::*;
+pub mod shape;
+pub use self::shape::*;
+pub mod prefixsum;
+pub use self::prefixsum::*;
+
+pub mod netpbm;
+
+#[cfg(feature = "serde")]
+pub mod serialize;
+
 pub mod astar;
+pub mod automaton;
 pub mod bf;
+pub mod bfs01;
+pub mod dial;
+pub mod flowfield;
+pub mod maze;
+#[cfg(feature = "rayon")]
+pub mod parallel;
+pub mod polygon;
 pub mod ucs;
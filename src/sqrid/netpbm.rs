@@ -0,0 +1,400 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+//! Image export and import in the netpbm formats
+//!
+//! Grids too large to read in a terminal are easier to inspect as
+//! images. This module adds methods that write and read the
+//! [netpbm](https://netpbm.sourceforge.net/doc/) formats, which most
+//! image viewers and converters support:
+//! - [`Gridbool::to_pbm`] and [`Gridbool::from_pbm`] use PBM bitmaps,
+//!   with `true` as black;
+//! - [`Grid::to_pgm`] and [`Grid::from_pgm`] use PGM grayscale
+//!   images, converting the cells with a function from/to `u8`;
+//! - [`Grid::to_ppm`] and [`Grid::from_ppm`] use PPM color images,
+//!   converting the cells with a function from/to `(r, g, b)`.
+//!
+//! The `_scaled` variants draw each cell as a `scale` x `scale` square
+//! of pixels, and read images drawn that way by sampling the top-left
+//! pixel of each square. The export functions panic if the scaled
+//! dimensions overflow `usize`.
+//!
+//! The images are written in the binary variants of the formats (`P4`,
+//! `P5` and `P6`); the parsers also accept the plain ones (`P1`, `P2`
+//! and `P3`), along with any `maxval`, rescaling the samples to `u8`.
+//! Parsing fails with [`Error::ImageParseError`] if the image is
+//! invalid, and with [`Error::OutOfBounds`] if its dimensions don't
+//! match the grid.
+//!
+//! Example usage:
+//!
+//! ```
+//! type Sqrid = sqrid::sqrid_create!(2, 1, false);
+//! type Pos = sqrid::pos_create!(Sqrid);
+//! type Grid = sqrid::grid_create!(Sqrid, u8);
+//! type Gridbool = sqrid::gridbool_create!(Sqrid);
+//!
+//! let gb = [Pos::TOP_LEFT, Pos::BOTTOM_RIGHT]
+//!     .into_iter()
+//!     .collect::<Gridbool>();
+//! let pbm = gb.to_pbm();
+//! assert_eq!(pbm, b"P4\n3 2\n\x80\x20");
+//! assert_eq!(Gridbool::from_pbm(&pbm)?, gb);
+//!
+//! let grid = Grid::from_pgm(b"P2 3 2 9 0 1 2 3 4 9", |v| v)?;
+//! assert_eq!(grid[Pos::BOTTOM_RIGHT], 255);
+//! let pgm = grid.to_pgm_scaled(10, |&v| v);
+//! assert_eq!(Grid::from_pgm_scaled(&pgm, 10, |v| v)?, grid);
+//! # Ok::<(), sqrid::Error>(())
+//! ```
+
+use alloc::format;
+use alloc::vec::Vec;
+
+use super::error::Error;
+use super::grid::Grid;
+use super::gridbool::Gridbool;
+use super::postrait::PosT;
+
+/* Export *********************************************************************/
+
+/// Return the dimension `len` scaled by `scale`
+///
+/// Panics if the result overflows `usize`.
+fn scaled(len: usize, scale: usize) -> usize {
+    len.checked_mul(scale)
+        .expect("scaled image dimensions overflow usize")
+}
+
+/// Write a binary PGM (`P5`) or PPM (`P6`) image of the `width` x
+/// `height` cells returned by `cell`, scaled by `scale`
+fn to_raw<const N: usize>(
+    magic: char,
+    width: usize,
+    height: usize,
+    scale: usize,
+    cell: impl Fn(usize) -> [u8; N],
+) -> Vec<u8> {
+    let (swidth, sheight) = (scaled(width, scale), scaled(height, scale));
+    let mut image = format!("P{}\n{} {}\n255\n", magic, swidth, sheight).into_bytes();
+    for y in 0..sheight {
+        for x in 0..swidth {
+            image.extend(cell(y / scale * width + x / scale));
+        }
+    }
+    image
+}
+
+/* Import *********************************************************************/
+
+/// A parsed netpbm image
+struct Image {
+    width: usize,
+    height: usize,
+    maxval: usize,
+    /// All samples, in row-major order, with 3 per pixel in PPM
+    samples: Vec<usize>,
+}
+
+/// Skip whitespace and comments
+fn skip(data: &[u8], i: &mut usize) {
+    while let Some(&c) = data.get(*i) {
+        if c == b'#' {
+            while data.get(*i).is_some_and(|&c| c != b'\n') {
+                *i += 1;
+            }
+        } else if c.is_ascii_whitespace() {
+            *i += 1;
+        } else {
+            break;
+        }
+    }
+}
+
+/// Parse an ASCII decimal number, skipping whitespace and comments before it
+fn number(data: &[u8], i: &mut usize) -> Result<usize, Error> {
+    skip(data, i);
+    let start = *i;
+    let mut value = 0_usize;
+    while let Some(&c) = data.get(*i).filter(|c| c.is_ascii_digit()) {
+        value = value
+            .checked_mul(10)
+            .and_then(|v| v.checked_add(usize::from(c - b'0')))
+            .ok_or(Error::ImageParseError)?;
+        *i += 1;
+    }
+    if *i == start {
+        return Err(Error::ImageParseError);
+    }
+    Ok(value)
+}
+
+impl Image {
+    /// Parse an image with one of the provided `magics`, checking that
+    /// it has the dimensions of `P` scaled by `scale`
+    ///
+    /// The dimensions are checked before reading the raster, so that
+    /// we never allocate based on untrusted header values.
+    fn parse<P: PosT>(data: &[u8], magics: [u8; 2], scale: usize) -> Result<Image, Error> {
+        let magic = match data {
+            [b'P', magic @ b'1'..=b'6', ..] => *magic,
+            _ => return Err(Error::ImageParseError),
+        };
+        if !magics.contains(&magic) {
+            return Err(Error::ImageParseError);
+        }
+        let mut i = 2;
+        let width = number(data, &mut i)?;
+        let height = number(data, &mut i)?;
+        if scale == 0
+            || P::width().checked_mul(scale) != Some(width)
+            || P::height().checked_mul(scale) != Some(height)
+        {
+            return Err(Error::OutOfBounds);
+        }
+        let maxval = match magic {
+            b'1' | b'4' => 1,
+            _ => number(data, &mut i)?,
+        };
+        if maxval == 0 || maxval > 65535 {
+            return Err(Error::ImageParseError);
+        }
+        let channels = if matches!(magic, b'3' | b'6') { 3 } else { 1 };
+        let count = width
+            .checked_mul(height)
+            .and_then(|pixels| pixels.checked_mul(channels))
+            .ok_or(Error::ImageParseError)?;
+        if matches!(magic, b'4'..=b'6') {
+            // A single whitespace separates the header from the raster:
+            if !data.get(i).is_some_and(u8::is_ascii_whitespace) {
+                return Err(Error::ImageParseError);
+            }
+            i += 1;
+        }
+        let raster = &data[i..];
+        let samples = match magic {
+            b'1' => {
+                // Plain PBM samples don't need to be separated:
+                let mut samples = Vec::new();
+                for _ in 0..count {
+                    skip(data, &mut i);
+                    match data.get(i) {
+                        Some(b'0') => samples.push(0),
+                        Some(b'1') => samples.push(1),
+                        _ => return Err(Error::ImageParseError),
+                    }
+                    i += 1;
+                }
+                samples
+            }
+            b'2' | b'3' => {
+                let mut samples = Vec::new();
+                for _ in 0..count {
+                    samples.push(number(data, &mut i)?);
+                }
+                samples
+            }
+            b'4' => {
+                let rowbytes = width.div_ceil(8);
+                if rowbytes
+                    .checked_mul(height)
+                    .filter(|&len| len <= raster.len())
+                    .is_none()
+                {
+                    return Err(Error::ImageParseError);
+                }
+                (0..count)
+                    .map(|p| {
+                        let byte = raster[p / width * rowbytes + p % width / 8];
+                        usize::from(byte >> (7 - p % width % 8) & 1)
+                    })
+                    .collect()
+            }
+            _ => {
+                let bytes = if maxval < 256 { 1 } else { 2 };
+                let len = count
+                    .checked_mul(bytes)
+                    .filter(|&len| len <= raster.len())
+                    .ok_or(Error::ImageParseError)?;
+                raster[..len]
+                    .chunks(bytes)
+                    .map(|b| b.iter().fold(0, |v, &b| v << 8 | usize::from(b)))
+                    .collect()
+            }
+        };
+        if samples.iter().any(|&s| s > maxval) {
+            return Err(Error::ImageParseError);
+        }
+        Ok(Image {
+            width,
+            height,
+            maxval,
+            samples,
+        })
+    }
+
+    /// Return the samples of the top-left pixel of each cell of an
+    /// image returned by [`Image::parse`] with the same `P` and `scale`
+    fn cells<P: PosT>(&self, scale: usize) -> impl Iterator<Item = &[usize]> {
+        let channels = self.samples.len() / (self.width * self.height);
+        (0..P::height()).flat_map(move |y| {
+            (0..P::width()).map(move |x| {
+                let p = (y * scale * self.width + x * scale) * channels;
+                &self.samples[p..p + channels]
+            })
+        })
+    }
+
+    /// Rescale a sample to `u8`
+    fn to_u8(&self, sample: usize) -> u8 {
+        ((sample * 255 + self.maxval / 2) / self.maxval) as u8
+    }
+}
+
+/* Gridbool *******************************************************************/
+
+impl<P: PosT, const WORDS: usize> Gridbool<P, WORDS> {
+    /// Export as a binary PBM image, with `true` as black
+    pub fn to_pbm(&self) -> Vec<u8> {
+        self.to_pbm_scaled(1)
+    }
+
+    /// Export as a binary PBM image, with `true` as black and each cell
+    /// as a `scale` x `scale` square
+    ///
+    /// Panics if the scaled dimensions overflow `usize`.
+    pub fn to_pbm_scaled(&self, scale: usize) -> Vec<u8> {
+        let cells = self.iter().collect::<Vec<_>>();
+        let (width, height) = (scaled(P::width(), scale), scaled(P::height(), scale));
+        let mut image = format!("P4\n{} {}\n", width, height).into_bytes();
+        for y in 0..height {
+            for bx in 0..width.div_ceil(8) {
+                let mut byte = 0_u8;
+                for x in (bx * 8..width).take(8) {
+                    if cells[y / scale * P::width() + x / scale] {
+                        byte |= 0x80 >> (x % 8);
+                    }
+                }
+                image.push(byte);
+            }
+        }
+        image
+    }
+
+    /// Import from a PBM image, with black as `true`
+    pub fn from_pbm(data: &[u8]) -> Result<Self, Error> {
+        Self::from_pbm_scaled(data, 1)
+    }
+
+    /// Import from a PBM image with each cell drawn as a `scale` x
+    /// `scale` square, with black as `true`
+    pub fn from_pbm_scaled(data: &[u8], scale: usize) -> Result<Self, Error> {
+        let image = Image::parse::<P>(data, [b'1', b'4'], scale)?;
+        let cells = image.cells::<P>(scale);
+        Ok(cells.map(|s| s[0] == 1).collect())
+    }
+}
+
+/* Grid ***********************************************************************/
+
+impl<T, P: PosT, const SIZE: usize> Grid<T, P, SIZE> {
+    /// Export as a binary PGM image, using `f` to get the gray
+    /// level of each cell
+    pub fn to_pgm<F>(&self, f: F) -> Vec<u8>
+    where
+        F: Fn(&T) -> u8,
+    {
+        self.to_pgm_scaled(1, f)
+    }
+
+    /// Export as a binary PGM image with each cell as a `scale` x
+    /// `scale` square, using `f` to get the gray level of each cell
+    ///
+    /// Panics if the scaled dimensions overflow `usize`.
+    pub fn to_pgm_scaled<F>(&self, scale: usize, f: F) -> Vec<u8>
+    where
+        F: Fn(&T) -> u8,
+    {
+        let cells = self.iter().map(|t| [f(t)]).collect::<Vec<_>>();
+        to_raw('5', P::width(), P::height(), scale, |i| cells[i])
+    }
+
+    /// Export as a binary PPM image, using `f` to get the `(r, g, b)`
+    /// color of each cell
+    pub fn to_ppm<F>(&self, f: F) -> Vec<u8>
+    where
+        F: Fn(&T) -> (u8, u8, u8),
+    {
+        self.to_ppm_scaled(1, f)
+    }
+
+    /// Export as a binary PPM image with each cell as a `scale` x
+    /// `scale` square, using `f` to get the `(r, g, b)` color of each
+    /// cell
+    ///
+    /// Panics if the scaled dimensions overflow `usize`.
+    pub fn to_ppm_scaled<F>(&self, scale: usize, f: F) -> Vec<u8>
+    where
+        F: Fn(&T) -> (u8, u8, u8),
+    {
+        let cells = self
+            .iter()
+            .map(|t| {
+                let (r, g, b) = f(t);
+                [r, g, b]
+            })
+            .collect::<Vec<_>>();
+        to_raw('6', P::width(), P::height(), scale, |i| cells[i])
+    }
+
+    /// Import from a PGM image, using `f` to convert the gray level of
+    /// each cell
+    pub fn from_pgm<F>(data: &[u8], f: F) -> Result<Self, Error>
+    where
+        T: Default,
+        F: Fn(u8) -> T,
+    {
+        Self::from_pgm_scaled(data, 1, f)
+    }
+
+    /// Import from a PGM image with each cell drawn as a `scale` x
+    /// `scale` square, using `f` to convert the gray level of each cell
+    pub fn from_pgm_scaled<F>(data: &[u8], scale: usize, f: F) -> Result<Self, Error>
+    where
+        T: Default,
+        F: Fn(u8) -> T,
+    {
+        let image = Image::parse::<P>(data, [b'2', b'5'], scale)?;
+        let cells = image.cells::<P>(scale);
+        Ok(cells.map(|s| f(image.to_u8(s[0]))).collect())
+    }
+
+    /// Import from a PPM image, using `f` to convert the `(r, g, b)`
+    /// color of each cell
+    pub fn from_ppm<F>(data: &[u8], f: F) -> Result<Self, Error>
+    where
+        T: Default,
+        F: Fn((u8, u8, u8)) -> T,
+    {
+        Self::from_ppm_scaled(data, 1, f)
+    }
+
+    /// Import from a PPM image with each cell drawn as a `scale` x
+    /// `scale` square, using `f` to convert the `(r, g, b)` color of
+    /// each cell
+    pub fn from_ppm_scaled<F>(data: &[u8], scale: usize, f: F) -> Result<Self, Error>
+    where
+        T: Default,
+        F: Fn((u8, u8, u8)) -> T,
+    {
+        let image = Image::parse::<P>(data, [b'3', b'6'], scale)?;
+        let cells = image.cells::<P>(scale);
+        Ok(cells
+            .map(|s| f((image.to_u8(s[0]), image.to_u8(s[1]), image.to_u8(s[2]))))
+            .collect())
+    }
+}
@@ -0,0 +1,250 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+//! Signed offsets between positions
+//!
+//! An [`Offset`] is a vector with signed `dx` and `dy` components. It's what we get by
+//! subtracting two [`Pos`], and what we can add to a [`Pos`] to move it more than one step at
+//! a time. Offsets can be added, subtracted, negated and multiplied by scalars; multiplying a
+//! [`Dir`] by a scalar also creates an offset.
+//!
+//! The arithmetic operators of [`Offset`] follow the overflow rules of `i32`: they panic in
+//! debug builds and wrap in release builds. The `checked_*` methods return `None` instead.
+//! Adding an offset to a [`Pos`] or subtracting it never overflows, returning
+//! [`Error::OutOfBounds`] if the result is not a valid position.
+//!
+//! Example usage:
+//!
+//! ```
+//! use sqrid::Dir;
+//! use sqrid::Offset;
+//!
+//! type Pos = sqrid::Pos<9, 9>;
+//!
+//! let pos1 = Pos::new(2, 3)?;
+//! let pos2 = Pos::new(5, 1)?;
+//! let offset = pos2 - pos1;
+//! assert_eq!(offset, Offset::new(3, -2));
+//! assert_eq!(pos1 + offset, Ok(pos2));
+//! assert_eq!(pos1 + Dir::E * 7, Ok(Pos::new(9, 3)?));
+//! assert!((pos1 + offset * 3).is_err());
+//! # Ok::<(), sqrid::Error>(())
+//! ```
+
+use core::fmt;
+use core::ops;
+
+use super::dir::Dir;
+use super::error::Error;
+use super::pos::Pos;
+
+/// A signed vector between positions
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Offset {
+    /// Horizontal component, positive to the east
+    pub dx: i32,
+    /// Vertical component, positive to the south
+    pub dy: i32,
+}
+
+impl Offset {
+    /// The offset that doesn't move
+    pub const ZERO: Offset = Offset::new(0, 0);
+
+    /// Create a new offset with the provided components
+    #[inline]
+    pub const fn new(dx: i32, dy: i32) -> Offset {
+        Offset { dx, dy }
+    }
+
+    /// Return the manhattan length of the offset
+    #[inline]
+    pub const fn manhattan(&self) -> u32 {
+        self.dx.unsigned_abs() + self.dy.unsigned_abs()
+    }
+
+    /// Return the length of the offset when diagonal steps are
+    /// allowed, also known as the Chebyshev distance
+    #[inline]
+    pub const fn chebyshev(&self) -> u32 {
+        let dx = self.dx.unsigned_abs();
+        let dy = self.dy.unsigned_abs();
+        if dx > dy {
+            dx
+        } else {
+            dy
+        }
+    }
+
+    /// Add two offsets, returning `None` on overflow
+    #[inline]
+    pub const fn checked_add(self, other: Offset) -> Option<Offset> {
+        match (self.dx.checked_add(other.dx), self.dy.checked_add(other.dy)) {
+            (Some(dx), Some(dy)) => Some(Offset::new(dx, dy)),
+            _ => None,
+        }
+    }
+
+    /// Subtract two offsets, returning `None` on overflow
+    #[inline]
+    pub const fn checked_sub(self, other: Offset) -> Option<Offset> {
+        match (self.dx.checked_sub(other.dx), self.dy.checked_sub(other.dy)) {
+            (Some(dx), Some(dy)) => Some(Offset::new(dx, dy)),
+            _ => None,
+        }
+    }
+
+    /// Negate the offset, returning `None` on overflow
+    #[inline]
+    pub const fn checked_neg(self) -> Option<Offset> {
+        match (self.dx.checked_neg(), self.dy.checked_neg()) {
+            (Some(dx), Some(dy)) => Some(Offset::new(dx, dy)),
+            _ => None,
+        }
+    }
+
+    /// Multiply the offset by a scalar, returning `None` on overflow
+    #[inline]
+    pub const fn checked_mul(self, n: i32) -> Option<Offset> {
+        match (self.dx.checked_mul(n), self.dy.checked_mul(n)) {
+            (Some(dx), Some(dy)) => Some(Offset::new(dx, dy)),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Offset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({},{})", self.dx, self.dy)
+    }
+}
+
+// Conversions
+
+impl From<(i32, i32)> for Offset {
+    #[inline]
+    fn from((dx, dy): (i32, i32)) -> Self {
+        Offset { dx, dy }
+    }
+}
+
+impl From<Offset> for (i32, i32) {
+    #[inline]
+    fn from(offset: Offset) -> Self {
+        (offset.dx, offset.dy)
+    }
+}
+
+impl From<Dir> for Offset {
+    #[inline]
+    fn from(dir: Dir) -> Self {
+        Offset::from(<(i32, i32)>::from(dir))
+    }
+}
+
+impl TryFrom<Offset> for Dir {
+    type Error = Error;
+    #[inline]
+    fn try_from(offset: Offset) -> Result<Self, Self::Error> {
+        Dir::try_from(&(offset.dx, offset.dy))
+    }
+}
+
+// Offset ops
+
+impl ops::Add for Offset {
+    type Output = Offset;
+    #[inline]
+    fn add(self, other: Offset) -> Offset {
+        Offset::new(self.dx + other.dx, self.dy + other.dy)
+    }
+}
+
+impl ops::AddAssign for Offset {
+    #[inline]
+    fn add_assign(&mut self, other: Offset) {
+        *self = *self + other;
+    }
+}
+
+impl ops::Sub for Offset {
+    type Output = Offset;
+    #[inline]
+    fn sub(self, other: Offset) -> Offset {
+        Offset::new(self.dx - other.dx, self.dy - other.dy)
+    }
+}
+
+impl ops::SubAssign for Offset {
+    #[inline]
+    fn sub_assign(&mut self, other: Offset) {
+        *self = *self - other;
+    }
+}
+
+impl ops::Neg for Offset {
+    type Output = Offset;
+    #[inline]
+    fn neg(self) -> Offset {
+        Offset::new(-self.dx, -self.dy)
+    }
+}
+
+impl ops::Mul<i32> for Offset {
+    type Output = Offset;
+    #[inline]
+    fn mul(self, n: i32) -> Offset {
+        Offset::new(self.dx * n, self.dy * n)
+    }
+}
+
+impl ops::Mul<i32> for Dir {
+    type Output = Offset;
+    #[inline]
+    fn mul(self, n: i32) -> Offset {
+        Offset::from(self) * n
+    }
+}
+
+// Pos ops
+
+impl<const XMAX: u16, const YMAX: u16> ops::Sub for Pos<XMAX, YMAX> {
+    type Output = Offset;
+    #[inline]
+    fn sub(self, other: Self) -> Offset {
+        Offset::new(
+            self.x() as i32 - other.x() as i32,
+            self.y() as i32 - other.y() as i32,
+        )
+    }
+}
+
+impl<const XMAX: u16, const YMAX: u16> ops::Add<Offset> for Pos<XMAX, YMAX> {
+    type Output = Result<Self, Error>;
+    #[inline]
+    fn add(self, offset: Offset) -> Self::Output {
+        let x = (self.x() as i32).checked_add(offset.dx);
+        let y = (self.y() as i32).checked_add(offset.dy);
+        match (x, y) {
+            (Some(x), Some(y)) => Self::try_from((x, y)),
+            _ => Err(Error::OutOfBounds),
+        }
+    }
+}
+
+impl<const XMAX: u16, const YMAX: u16> ops::Sub<Offset> for Pos<XMAX, YMAX> {
+    type Output = Result<Self, Error>;
+    #[inline]
+    fn sub(self, offset: Offset) -> Self::Output {
+        let x = (self.x() as i32).checked_sub(offset.dx);
+        let y = (self.y() as i32).checked_sub(offset.dy);
+        match (x, y) {
+            (Some(x), Some(y)) => Self::try_from((x, y)),
+            _ => Err(Error::OutOfBounds),
+        }
+    }
+}
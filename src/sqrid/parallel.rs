@@ -0,0 +1,348 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+//! Parallel grid operations using [`rayon`], enabled by the `rayon` feature
+//!
+//! This module adds [`Grid::par_iter_pos`] and [`Grid::par_map`], that
+//! work like their sequential counterparts on rayon's thread pool, and a
+//! few algorithms that split the work among threads:
+//! - [`components`] labels the connected components of a
+//!   [`Gridbool`] by flood-filling strips of rows in parallel and then
+//!   merging the labels across the strip boundaries;
+//! - [`bf_distances`] and [`ucs_distances`] compute the distances from
+//!   each one of a set of origins to each one of a set of destinations,
+//!   running the independent searches concurrently.
+//!
+//! The grids returned here are boxed, as these operations are only worth
+//! it for grids that are too big for the stack.
+//!
+//! Example usage:
+//!
+//! ```
+//! type Sqrid = sqrid::sqrid_create!(3, 2, false);
+//! type Pos = sqrid::pos_create!(Sqrid);
+//! type Gridbool = sqrid::gridbool_create!(Sqrid);
+//!
+//! // #.##
+//! // #..#
+//! // ..#.
+//! let walls = [(0, 0), (2, 0), (3, 0), (0, 1), (3, 1), (2, 2)];
+//! let gb = walls
+//!     .into_iter()
+//!     .map(|(x, y)| Pos::new(x, y))
+//!     .collect::<Result<Gridbool, _>>()?;
+//! let (labels, count) = Sqrid::par_components(&gb);
+//! assert_eq!(count, 3);
+//! assert_eq!(labels[Pos::TOP_LEFT], 1);
+//! assert_eq!(labels[Pos::TOP_RIGHT], 2);
+//! assert_eq!(labels[Pos::BOTTOM_RIGHT], 0);
+//!
+//! let go = |pos: Pos, dir: sqrid::Dir| (pos + dir).ok().filter(|p| !gb.get(p));
+//! let origins = [Pos::new(1, 0)?, Pos::new(3, 2)?];
+//! let distances = Sqrid::par_bf_distances(go, &origins, &[Pos::BOTTOM_LEFT]);
+//! assert_eq!(distances, vec![vec![Some(3)], vec![None]]);
+//! # Ok::<(), sqrid::Error>(())
+//! ```
+
+use std::collections::HashMap;
+
+use rayon::prelude::*;
+
+use super::bf::bf_iter;
+use super::postrait::PosT;
+use super::ucs::Cost;
+use super::ucs::UcsIterator;
+use super::Dir;
+use super::Grid;
+use super::Gridbool;
+use super::Sqrid;
+
+/* Grid parallel iteration and mapping ****************************************/
+
+impl<T, P: PosT, const SIZE: usize> Grid<T, P, SIZE> {
+    /// Return a parallel iterator over all positions and
+    /// corresponding values
+    pub fn par_iter_pos(&self) -> impl IndexedParallelIterator<Item = (P, &T)> + '_
+    where
+        T: Sync,
+        P: Send,
+    {
+        self.as_array()
+            .par_iter()
+            .enumerate()
+            .map(|(i, t)| (P::tryfrom_usize(i).unwrap(), t))
+    }
+
+    /// Create a new grid with the results of calling `f` on every
+    /// value, in parallel
+    pub fn par_map<U, F>(&self, f: F) -> Box<Grid<U, P, SIZE>>
+    where
+        T: Sync,
+        U: Send,
+        F: Fn(&T) -> U + Sync + Send,
+    {
+        Grid::boxed_from_vec(self.as_array().par_iter().map(f).collect())
+    }
+}
+
+/* Connected components *******************************************************/
+
+/// Find the representative of `label`, halving the path on the way
+fn find(parent: &mut [usize], mut label: usize) -> usize {
+    while parent[label] != label {
+        parent[label] = parent[parent[label]];
+        label = parent[label];
+    }
+    label
+}
+
+/// Label the connected components of the `true` positions of `gridbool`
+///
+/// Returns the grid of labels, where `0` means `false` and the
+/// components are numbered from `1` in the order their first position
+/// appears, along with the number of components. Diagonal neighbors
+/// are connected if `D` is `true`.
+pub fn components<P, const D: bool, const WORDS: usize, const SIZE: usize>(
+    gridbool: &Gridbool<P, WORDS>,
+) -> (Box<Grid<usize, P, SIZE>>, usize)
+where
+    P: PosT,
+{
+    let width = P::width();
+    let height = P::height();
+    let cells = gridbool.iter().collect::<Vec<_>>();
+    let mut labels = vec![0_usize; SIZE];
+    // Flood-fill each strip of rows independently, labeling every
+    // component with the index of its first position plus one:
+    let rows = height.div_ceil(rayon::current_num_threads()).max(1);
+    labels
+        .par_chunks_mut(rows * width)
+        .enumerate()
+        .for_each(|(strip, strip_labels)| {
+            let base = strip * rows * width;
+            let strip_height = strip_labels.len() / width;
+            let mut stack = Vec::new();
+            for seed in 0..strip_labels.len() {
+                if !cells[base + seed] || strip_labels[seed] != 0 {
+                    continue;
+                }
+                let label = base + seed + 1;
+                strip_labels[seed] = label;
+                stack.push(seed);
+                while let Some(i) = stack.pop() {
+                    let (x, y) = (i % width, i / width);
+                    for dy in -1_isize..=1 {
+                        for dx in -1_isize..=1 {
+                            if (dx == 0 && dy == 0) || (!D && dx != 0 && dy != 0) {
+                                continue;
+                            }
+                            let (Some(nx), Some(ny)) =
+                                (x.checked_add_signed(dx), y.checked_add_signed(dy))
+                            else {
+                                continue;
+                            };
+                            if nx >= width || ny >= strip_height {
+                                continue;
+                            }
+                            let j = ny * width + nx;
+                            if cells[base + j] && strip_labels[j] == 0 {
+                                strip_labels[j] = label;
+                                stack.push(j);
+                            }
+                        }
+                    }
+                }
+            }
+        });
+    // Merge the components that touch across strip boundaries, keeping
+    // the smallest label as the representative:
+    let mut parent = (0..=SIZE).collect::<Vec<_>>();
+    for y in (rows..height).step_by(rows) {
+        for x in 0..width {
+            let label = labels[y * width + x];
+            if label == 0 {
+                continue;
+            }
+            let xmin = if D { x.saturating_sub(1) } else { x };
+            let xmax = if D { (x + 1).min(width - 1) } else { x };
+            for nx in xmin..=xmax {
+                let other = labels[(y - 1) * width + nx];
+                if other == 0 {
+                    continue;
+                }
+                let (a, b) = (find(&mut parent, label), find(&mut parent, other));
+                parent[a.max(b)] = a.min(b);
+            }
+        }
+    }
+    // Number the components in the order of their representatives, that
+    // are also the order of their first positions:
+    let seeds = labels
+        .par_iter()
+        .enumerate()
+        .filter(|&(i, &label)| label == i + 1)
+        .map(|(_, &label)| label)
+        .collect::<Vec<_>>();
+    let mut compact = vec![0_usize; SIZE + 1];
+    let mut count = 0;
+    for seed in seeds {
+        let root = find(&mut parent, seed);
+        compact[seed] = if root == seed {
+            count += 1;
+            count
+        } else {
+            compact[root]
+        };
+    }
+    labels.par_iter_mut().for_each(|label| {
+        *label = compact[*label];
+    });
+    (Grid::boxed_from_vec(labels), count)
+}
+
+/* Many-to-many distances *****************************************************/
+
+/// Map the index of each destination position to the indexes of the
+/// destinations at it
+fn dests_index<P: PosT>(dests: &[P]) -> HashMap<usize, Vec<usize>> {
+    let mut index = HashMap::<usize, Vec<usize>>::new();
+    for (i, dest) in dests.iter().enumerate() {
+        index.entry(dest.to_usize()).or_default().push(i);
+    }
+    index
+}
+
+/// Compute the breadth-first distances from each origin to each
+/// destination, running the searches in parallel
+///
+/// Returns a vector with one entry per origin, each one with the
+/// distance to each destination, or `None` if it can't be reached.
+/// Each search stops as soon as all destinations are found.
+pub fn bf_distances<GoFn, P, const D: bool, const WORDS: usize, const SIZE: usize>(
+    go: GoFn,
+    origins: &[P],
+    dests: &[P],
+) -> Vec<Vec<Option<usize>>>
+where
+    GoFn: Fn(P, Dir) -> Option<P> + Sync,
+    P: PosT,
+    P: Copy,
+    P: Send + Sync,
+{
+    let index = dests_index(dests);
+    origins
+        .par_iter()
+        .map(|orig| {
+            let mut distances = vec![None; dests.len()];
+            let mut remaining = dests.len();
+            let mut found = |pos: &P, distance: usize| {
+                for &i in index.get(&pos.to_usize()).into_iter().flatten() {
+                    distances[i] = Some(distance);
+                    remaining -= 1;
+                }
+                remaining == 0
+            };
+            if !found(orig, 0) {
+                let it = bf_iter::<&GoFn, Box<Gridbool<P, WORDS>>, P, D, WORDS, SIZE>(&go, orig);
+                'search: for (distance, front) in it.enumerate() {
+                    for (pos, _) in front {
+                        if found(&pos, distance + 1) {
+                            break 'search;
+                        }
+                    }
+                }
+            }
+            distances
+        })
+        .collect()
+}
+
+/// Compute the uniform-cost distances from each origin to each
+/// destination, running the searches in parallel
+///
+/// Returns a vector with one entry per origin, each one with the cost
+/// of reaching each destination, or `None` if it can't be reached.
+/// Each search stops as soon as all destinations are found.
+pub fn ucs_distances<F, P, const D: bool, const WORDS: usize, const SIZE: usize>(
+    go: F,
+    origins: &[P],
+    dests: &[P],
+) -> Vec<Vec<Option<Cost>>>
+where
+    F: Fn(P, Dir) -> Option<(P, Cost)> + Sync,
+    P: PosT,
+    P: Ord,
+    P: Copy,
+    P: Send + Sync,
+{
+    let index = dests_index(dests);
+    origins
+        .par_iter()
+        .map(|orig| {
+            let mut costs = vec![None; dests.len()];
+            let mut remaining = dests.len();
+            if remaining == 0 {
+                return costs;
+            }
+            let it =
+                UcsIterator::<&F, Box<Grid<usize, P, SIZE>>, P, D, WORDS, SIZE>::new(&go, orig);
+            for (pos, _, cost) in it {
+                for &i in index.get(&pos.to_usize()).into_iter().flatten() {
+                    costs[i] = Some(cost);
+                    remaining -= 1;
+                }
+                if remaining == 0 {
+                    break;
+                }
+            }
+            costs
+        })
+        .collect()
+}
+
+/* Sqrid plugin: **************************************************************/
+
+impl<const W: u16, const H: u16, const D: bool, const WORDS: usize, const SIZE: usize>
+    Sqrid<W, H, D, WORDS, SIZE>
+{
+    /// Label the connected components of a [`Gridbool`] in parallel;
+    /// see [`parallel::components`](crate::parallel::components).
+    pub fn par_components<P: PosT>(
+        gridbool: &Gridbool<P, WORDS>,
+    ) -> (Box<Grid<usize, P, SIZE>>, usize) {
+        components::<P, D, WORDS, SIZE>(gridbool)
+    }
+
+    /// Compute the breadth-first distances between many positions in
+    /// parallel; see [`parallel::bf_distances`](crate::parallel::bf_distances).
+    pub fn par_bf_distances<GoFn, P>(
+        go: GoFn,
+        origins: &[P],
+        dests: &[P],
+    ) -> Vec<Vec<Option<usize>>>
+    where
+        GoFn: Fn(P, Dir) -> Option<P> + Sync,
+        P: PosT,
+        P: Copy,
+        P: Send + Sync,
+    {
+        bf_distances::<GoFn, P, D, WORDS, SIZE>(go, origins, dests)
+    }
+
+    /// Compute the uniform-cost distances between many positions in
+    /// parallel; see [`parallel::ucs_distances`](crate::parallel::ucs_distances).
+    pub fn par_ucs_distances<F, P>(go: F, origins: &[P], dests: &[P]) -> Vec<Vec<Option<Cost>>>
+    where
+        F: Fn(P, Dir) -> Option<(P, Cost)> + Sync,
+        P: PosT,
+        P: Ord,
+        P: Copy,
+        P: Send + Sync,
+    {
+        ucs_distances::<F, P, D, WORDS, SIZE>(go, origins, dests)
+    }
+}
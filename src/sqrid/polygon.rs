@@ -0,0 +1,405 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+//! Area and interior of closed loops traced by [`Dir`] paths
+//!
+//! A path of [`Dir`] steps, or of `(Dir, length)` instructions, that returns to its start
+//! traces a polygon whose vertices are cells. [`area`] and [`area_path`] use the shoelace
+//! formula to calculate the area of that polygon, and Pick's theorem to get the number of
+//! cells strictly inside it.
+//!
+//! [`vertices`] returns the corners of the polygon, which can then be used to check where a
+//! point is with [`locate`], or to get a [`Gridbool`] with the interior cells with
+//! [`interior`].
+//!
+//! All coordinates are `i64`, and the intermediate calculations use `i128`, so that the
+//! loops can be much larger than any grid - only [`interior`] is limited by the grid size.
+//! [`vertices`] and [`area`] return [`Error::OutOfBounds`] if a result doesn't fit in an
+//! `i64`.
+//!
+//! For loops given as the sequence of their positions in a grid, like the ones found by
+//! walking pipe connections, [`Gridbool::fill_enclosed`] and [`Gridbool::fill_enclosed_flood`]
+//! set the positions inside the loop.
+//!
+//! Example usage:
+//!
+//! ```
+//! use sqrid::Dir;
+//! use sqrid::polygon;
+//!
+//! let steps = [(Dir::E, 4), (Dir::S, 2), (Dir::W, 4), (Dir::N, 2)];
+//! let area = polygon::area(steps)?;
+//! assert_eq!(area.boundary, 12);
+//! assert_eq!(area.interior, 3);
+//!
+//! let vertices = polygon::vertices((0, 0), steps)?;
+//! assert_eq!(polygon::locate(&vertices, (1, 1)), polygon::Location::Inside);
+//! assert_eq!(polygon::locate(&vertices, (4, 1)), polygon::Location::Boundary);
+//! assert_eq!(polygon::locate(&vertices, (5, 1)), polygon::Location::Outside);
+//! # Ok::<(), sqrid::Error>(())
+//! ```
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use super::bf;
+use super::postrait::PosT;
+use super::Dir;
+use super::Error;
+use super::Gridbool;
+use super::Sqrid;
+
+/// Area of a closed loop
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LoopArea {
+    /// Twice the area of the polygon that has the cells of the
+    /// loop as vertices, by the shoelace formula
+    pub double_area: i64,
+    /// Number of cells in the loop
+    pub boundary: i64,
+    /// Number of cells strictly inside the loop, by Pick's theorem
+    pub interior: i64,
+}
+
+impl LoopArea {
+    /// Number of cells in the loop or inside it
+    pub fn total(&self) -> i64 {
+        self.boundary + self.interior
+    }
+}
+
+/// Position of a point relative to a loop
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Location {
+    /// The point is strictly inside the loop
+    Inside,
+    /// The point is part of the loop
+    Boundary,
+    /// The point is outside of the loop
+    Outside,
+}
+
+/// Return the vertices of the path that starts at `start` and
+/// follows the provided `(Dir, length)` instructions
+///
+/// The first vertex is `start`, followed by the end of each
+/// instruction; the last one is equal to the first if the path is a
+/// closed loop. Returns [`Error::OutOfBounds`] if a vertex doesn't
+/// fit in an `i64`.
+pub fn vertices(
+    start: (i64, i64),
+    steps: impl IntoIterator<Item = (Dir, i64)>,
+) -> Result<Vec<(i64, i64)>, Error> {
+    let mut vertices = vec![start];
+    let mut current = start;
+    for (dir, length) in steps {
+        let (dx, dy) = <(i64, i64)>::from(dir);
+        let add = |c: i64, d: i64| {
+            i64::try_from(c as i128 + d as i128 * length as i128).map_err(|_| Error::OutOfBounds)
+        };
+        current = (add(current.0, dx)?, add(current.1, dy)?);
+        vertices.push(current);
+    }
+    Ok(vertices)
+}
+
+/// Return the area of the closed loop traced by the provided
+/// `(Dir, length)` instructions
+///
+/// The loop must not cross itself. Returns [`Error::NotClosed`] if
+/// the path doesn't end where it starts, and [`Error::OutOfBounds`]
+/// if the results don't fit in an `i64`.
+pub fn area(steps: impl IntoIterator<Item = (Dir, i64)>) -> Result<LoopArea, Error> {
+    let mut boundary = 0_i128;
+    let vertices = vertices(
+        (0, 0),
+        steps.into_iter().inspect(|(_, length)| {
+            boundary += length.unsigned_abs() as i128;
+        }),
+    )?;
+    if vertices.first() != vertices.last() {
+        return Err(Error::NotClosed);
+    }
+    let double_area = vertices
+        .windows(2)
+        .map(|w| w[0].0 as i128 * w[1].1 as i128 - w[1].0 as i128 * w[0].1 as i128)
+        .sum::<i128>()
+        .abs();
+    // Pick's theorem: A = I + B/2 - 1
+    let interior = (double_area - boundary + 2) / 2;
+    let convert = |value: i128| i64::try_from(value).map_err(|_| Error::OutOfBounds);
+    Ok(LoopArea {
+        double_area: convert(double_area)?,
+        boundary: convert(boundary)?,
+        interior: convert(interior.max(0))?,
+    })
+}
+
+/// Return the area of the closed loop traced by the provided path of
+/// single steps; see [`area`]
+pub fn area_path(path: &[Dir]) -> Result<LoopArea, Error> {
+    area(path.iter().map(|&dir| (dir, 1)))
+}
+
+/// Iterate over the edges of the polygon, closing it if the last
+/// vertex is not equal to the first
+fn edges(vertices: &[(i64, i64)]) -> impl Iterator<Item = ((i64, i64), (i64, i64))> + '_ {
+    let closing = match (vertices.first(), vertices.last()) {
+        (Some(&first), Some(&last)) if first != last => Some((last, first)),
+        _ => None,
+    };
+    vertices
+        .windows(2)
+        .map(|w| (w[0], w[1]))
+        .chain(closing)
+        .filter(|(a, b)| a != b)
+}
+
+/// Iterate over the edges of the polygon with `i128` coordinates, so
+/// that their differences don't overflow
+fn edges_wide(vertices: &[(i64, i64)]) -> impl Iterator<Item = ((i128, i128), (i128, i128))> + '_ {
+    edges(vertices).map(|((ax, ay), (bx, by))| ((ax as i128, ay as i128), (bx as i128, by as i128)))
+}
+
+/// Return the `x` where the edge crosses the horizontal line at `y`,
+/// counting the lower end of the edge but not the upper one
+fn crossing(edge: ((i128, i128), (i128, i128)), y: i128) -> Option<i128> {
+    let ((ax, ay), (bx, by)) = edge;
+    if (ay > y) == (by > y) {
+        return None;
+    }
+    // Edges are vertical or diagonal, so the slope is -1, 0 or 1:
+    let slope = (bx - ax) / (by - ay);
+    Some(ax + (y - ay) * slope)
+}
+
+/// Return the range of steps `t` along a coordinate that starts at
+/// `a` and changes by `d` (-1, 0 or 1) in each step that are in
+/// `lo..=hi`
+fn clip(a: i128, d: i128, lo: i128, hi: i128) -> (i128, i128) {
+    match d {
+        0 if (lo..=hi).contains(&a) => (0, i128::MAX),
+        0 => (1, 0),
+        1 => (lo - a, hi - a),
+        _ => (a - hi, a - lo),
+    }
+}
+
+/// Return where `point` is relative to the loop with the provided
+/// vertices
+///
+/// The edges of the loop must be horizontal, vertical or diagonal,
+/// as the ones created by [`vertices`].
+pub fn locate(vertices: &[(i64, i64)], point: (i64, i64)) -> Location {
+    let (px, py) = (point.0 as i128, point.1 as i128);
+    let on_edge = |((ax, ay), (bx, by)): ((i128, i128), (i128, i128))| {
+        let cross = (bx - ax) * (py - ay) - (by - ay) * (px - ax);
+        cross == 0 && ax.min(bx) <= px && px <= ax.max(bx) && ay.min(by) <= py && py <= ay.max(by)
+    };
+    if edges_wide(vertices).any(on_edge) {
+        return Location::Boundary;
+    }
+    let crossings = edges_wide(vertices)
+        .filter_map(|edge| crossing(edge, py))
+        .filter(|&x| x > px)
+        .count();
+    if crossings % 2 == 1 {
+        Location::Inside
+    } else {
+        Location::Outside
+    }
+}
+
+/// Return a [`Gridbool`] with the positions strictly inside the loop
+/// with the provided vertices
+///
+/// The vertices use the coordinates of the grid, but they don't
+/// have to be in it: only the part of the loop that overlaps the
+/// grid is considered. The edges of the loop must be horizontal,
+/// vertical or diagonal, as the ones created by [`vertices`].
+pub fn interior<P: PosT, const WORDS: usize>(vertices: &[(i64, i64)]) -> Gridbool<P, WORDS> {
+    let width = P::width() as i128;
+    let height = P::height() as i128;
+    let index = |x: i128, y: i128| P::tryfrom_usize((y * width + x) as usize).unwrap();
+    let mut gridbool = Gridbool::<P, WORDS>::ALL_FALSE;
+    // Fill the positions between pairs of crossings in each line:
+    for y in 0..height {
+        let mut crossings = edges_wide(vertices)
+            .filter_map(|edge| crossing(edge, y))
+            .collect::<Vec<_>>();
+        crossings.sort_unstable();
+        for pair in crossings.chunks_exact(2) {
+            for x in (pair[0] + 1).max(0)..pair[1].min(width) {
+                gridbool.set_t(&index(x, y));
+            }
+        }
+    }
+    // Clear the positions of the loop itself:
+    for ((ax, ay), (bx, by)) in edges_wide(vertices) {
+        let (dx, dy) = ((bx - ax).signum(), (by - ay).signum());
+        let (xmin, xmax) = clip(ax, dx, 0, width - 1);
+        let (ymin, ymax) = clip(ay, dy, 0, height - 1);
+        let steps = (bx - ax).abs().max((by - ay).abs());
+        for t in xmin.max(ymin).max(0)..=xmax.min(ymax).min(steps) {
+            gridbool.set_f(&index(ax + t * dx, ay + t * dy));
+        }
+    }
+    gridbool
+}
+
+/* Gridbool *******************************************************************/
+
+/// The positions of a loop, with their connections to the next
+/// position to the east and to the south
+#[derive(Debug)]
+struct Connections<P: PosT, const WORDS: usize> {
+    cells: Gridbool<P, WORDS>,
+    east: Gridbool<P, WORDS>,
+    south: Gridbool<P, WORDS>,
+}
+
+impl<P: PosT, const WORDS: usize> Connections<P, WORDS> {
+    fn new(path: &[P]) -> Self {
+        let width = P::width();
+        let mut connections = Connections {
+            cells: Gridbool::ALL_FALSE,
+            east: Gridbool::ALL_FALSE,
+            south: Gridbool::ALL_FALSE,
+        };
+        for (i, pos) in path.iter().enumerate() {
+            connections.cells.set_t(pos);
+            let a = pos.to_usize();
+            let b = path[(i + 1) % path.len()].to_usize();
+            let (first, last) = (a.min(b), a.max(b));
+            let first = P::tryfrom_usize(first).unwrap();
+            if last - first.to_usize() == width {
+                connections.south.set_t(&first);
+            } else if last - first.to_usize() == 1 && last % width != 0 {
+                connections.east.set_t(&first);
+            }
+        }
+        connections
+    }
+}
+
+impl<P: PosT, const WORDS: usize> Gridbool<P, WORDS> {
+    /// Set the positions strictly inside the loop formed by `path`,
+    /// using the parity of the crossings of each line
+    ///
+    /// Consecutive positions of `path`, including the last and the
+    /// first, must be orthogonal neighbors. The loop can touch the
+    /// border of the grid.
+    pub fn fill_enclosed(&mut self, path: &[P]) {
+        let connections = Connections::<P, WORDS>::new(path);
+        let width = P::width();
+        let mut inside = false;
+        for pos in P::iter() {
+            let i = pos.to_usize();
+            if i % width == 0 {
+                inside = false;
+            }
+            if !connections.cells[&pos] {
+                if inside {
+                    self.set_t(&pos);
+                }
+            } else if i >= width && connections.south[P::tryfrom_usize(i - width).unwrap()] {
+                // The line crosses the loop at positions connected to
+                // the north:
+                inside = !inside;
+            }
+        }
+    }
+
+    /// Set the positions strictly inside the loop formed by `path`,
+    /// by flood-filling the outside with [`bf_iter`](super::bf::bf_iter)
+    ///
+    /// The flood-fill goes through the corners of the positions, so
+    /// that it can squeeze between adjacent positions of the loop
+    /// that are not connected. See [`Gridbool::fill_enclosed`].
+    ///
+    /// `SIZE` is the number of positions of the grid; see
+    /// [`Sqrid::fill_enclosed_flood`] for a version that provides it.
+    pub fn fill_enclosed_flood<const SIZE: usize>(&mut self, path: &[P])
+    where
+        P: Copy,
+    {
+        let connections = Connections::<P, WORDS>::new(path);
+        let width = P::width();
+        let height = P::height();
+        let pos = |x: usize, y: usize| P::tryfrom_usize(y * width + x).unwrap();
+        // Each corner is represented by the position to its
+        // bottom-right. Moving between corners is blocked when the
+        // positions on both sides of the segment are connected:
+        let go = |corner: P, dir: Dir| {
+            let i = corner.to_usize();
+            let (x, y) = (i % width, i / width);
+            let (nx, ny, blocked) = match dir {
+                Dir::E => (x + 1, y, y > 0 && connections.south[pos(x, y - 1)]),
+                Dir::W => (
+                    x.checked_sub(1)?,
+                    y,
+                    y > 0 && connections.south[pos(x - 1, y - 1)],
+                ),
+                Dir::S => (x, y + 1, x > 0 && connections.east[pos(x - 1, y)]),
+                Dir::N => (
+                    x,
+                    y.checked_sub(1)?,
+                    x > 0 && connections.east[pos(x - 1, y - 1)],
+                ),
+                _ => return None,
+            };
+            (!blocked && nx < width && ny < height).then(|| pos(nx, ny))
+        };
+        // Corners on the top and left borders are outside, along with
+        // the ones that can be reached from the right and bottom
+        // borders, which are not represented:
+        let seeds = (0..width)
+            .map(|x| pos(x, 0))
+            .chain((0..height).map(|y| pos(0, y)))
+            .chain(
+                (0..height)
+                    .filter(|&y| y == 0 || !connections.south[pos(width - 1, y - 1)])
+                    .map(|y| pos(width - 1, y)),
+            )
+            .chain(
+                (0..width)
+                    .filter(|&x| x == 0 || !connections.east[pos(x - 1, height - 1)])
+                    .map(|x| pos(x, height - 1)),
+            );
+        let mut outside = Gridbool::<P, WORDS>::ALL_FALSE;
+        for seed in seeds {
+            if outside[&seed] {
+                continue;
+            }
+            outside.set_t(&seed);
+            let flood = bf::bf_iter::<_, Gridbool<P, WORDS>, P, false, WORDS, SIZE>(&go, &seed);
+            outside.set_iter_t(flood.flatten().map(|(corner, _)| corner));
+        }
+        for pos in P::iter() {
+            if !connections.cells[&pos] && !outside[&pos] {
+                self.set_t(&pos);
+            }
+        }
+    }
+}
+
+/* Sqrid plugin: **************************************************************/
+
+impl<const W: u16, const H: u16, const D: bool, const WORDS: usize, const SIZE: usize>
+    Sqrid<W, H, D, WORDS, SIZE>
+{
+    /// Set the positions of `gridbool` strictly inside the loop formed
+    /// by `path`, by flood-filling the outside;
+    /// see [`Gridbool::fill_enclosed_flood`].
+    pub fn fill_enclosed_flood<P>(gridbool: &mut Gridbool<P, WORDS>, path: &[P])
+    where
+        P: PosT,
+        P: Copy,
+    {
+        gridbool.fill_enclosed_flood::<SIZE>(path)
+    }
+}
@@ -11,8 +11,8 @@
 //! This submodule has the [`Pos`] type and the associated
 //! functionality.
 
-use std::convert;
-use std::fmt;
+use core::convert;
+use core::fmt;
 
 use super::boundedint::BoundedU16;
 use super::error::Error;
@@ -38,7 +38,7 @@ use super::postrait::PosT;
 /// - Using [`Pos::new`] with X and Y coordinates and handling the
 ///   `Result`; can also be used in const contexts.
 ///   ```rust
-///   # fn main() -> Result<(), Box<dyn std::error::Error>> {
+///   # fn main() -> Result<(), Box<dyn core::error::Error>> {
 ///   # type Pos = sqrid::Pos<4, 4>;
 ///   let pos = Pos::new(3, 3)?;
 ///   # Ok(()) }
@@ -46,9 +46,9 @@ use super::postrait::PosT;
 /// - Using `try_from` with a `(u16, u16)` tuple or a tuple
 ///   reference. It's equivalent to `Pos::new`:
 ///   ```rust
-///   # fn main() -> Result<(), Box<dyn std::error::Error>> {
+///   # fn main() -> Result<(), Box<dyn core::error::Error>> {
 ///   # type Pos = sqrid::Pos<4, 4>;
-///   use std::convert::{TryFrom, TryInto};
+///   use core::convert::{TryFrom, TryInto};
 ///   let pos1 = Pos::try_from((3, 3))?;
 ///   let pos2 : Pos = (3_u16, 3_u16).try_into()?;
 ///   # Ok(()) }
@@ -187,6 +187,13 @@ impl<const XMAX: u16, const YMAX: u16> Pos<XMAX, YMAX> {
     pub const fn tuple(&self) -> (u16, u16) {
         (self.x(), self.y())
     }
+
+    /// Return the position with the coordinates exchanged, in the
+    /// transposed grid
+    #[inline]
+    pub const fn transpose(&self) -> Pos<YMAX, XMAX> {
+        Pos::<YMAX, XMAX>((self.0 .1, self.0 .0))
+    }
 }
 
 impl<const XMAX: u16, const YMAX: u16> fmt::Display for Pos<XMAX, YMAX> {
@@ -233,7 +240,9 @@ impl<const XMAX: u16, const YMAX: u16> convert::TryFrom<&(i32, i32)> for Pos<XMA
     type Error = Error;
     #[inline]
     fn try_from(xy: &(i32, i32)) -> Result<Self, Self::Error> {
-        Pos::new(xy.0 as u16, xy.1 as u16)
+        let x = u16::try_from(xy.0).map_err(|_| Error::OutOfBounds)?;
+        let y = u16::try_from(xy.1).map_err(|_| Error::OutOfBounds)?;
+        Pos::new(x, y)
     }
 }
 
@@ -7,14 +7,16 @@
 
 //! Interaction between [`Pos`] and [`Dir`]
 
-use std::cmp::Ordering::{Equal, Greater, Less};
-use std::ops;
+use core::cmp::Ordering::{Equal, Greater, Less};
+use core::ops;
 
+use super::base::Sqrid;
 use super::boundedint;
 use super::dir::Dir;
 use super::error::Error;
 use super::pos::Pos;
 use super::postrait::PosT;
+use super::setpos::SetPos;
 
 impl<const XMAX: u16, const YMAX: u16> ops::Add<Dir> for Pos<XMAX, YMAX>
 where
@@ -109,3 +111,15 @@ pub fn direction_to<P: PosT, const D: bool>(src: &P, dst: &P) -> Option<Dir> {
         }
     }
 }
+
+/* Sqrid plugin: **************************************************************/
+
+impl<const W: u16, const H: u16, const D: bool, const WORDS: usize, const SIZE: usize>
+    Sqrid<W, H, D, WORDS, SIZE>
+{
+    /// Return the number of neighbors of `pos` that are in `set`;
+    /// see [`PosT::count_neighbors_in`]
+    pub fn count_neighbors_in<P: PosT>(pos: &P, set: &impl SetPos<P, WORDS, SIZE>) -> usize {
+        pos.count_neighbors_in::<D, WORDS, SIZE>(set)
+    }
+}
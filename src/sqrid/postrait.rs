@@ -5,7 +5,10 @@
 //! Position as a trait
 
 use super::boundedint::BoundedInt;
+use super::dir::Dir;
+use super::dir::DirIter;
 use super::error::Error;
+use super::setpos::SetPos;
 
 macro_rules! into_or_panic {
     ($e:expr) => {{
@@ -15,7 +18,7 @@ macro_rules! into_or_panic {
 }
 
 /// Position trait
-pub trait PosT: std::fmt::Debug + Default + Eq + PartialOrd + Copy {
+pub trait PosT: core::fmt::Debug + Default + Eq + PartialOrd + Copy {
     // User parameters:
 
     /// The type of the X coordinate
@@ -39,7 +42,7 @@ pub trait PosT: std::fmt::Debug + Default + Eq + PartialOrd + Copy {
         Y: BoundedInt,
         Self::Xtype: TryFrom<X>,
         Self::Ytype: TryFrom<Y>,
-        Self: std::marker::Sized,
+        Self: core::marker::Sized,
     {
         let x = Self::Xtype::try_from(x).map_err(|_| Error::OutOfBounds)?;
         let y = Self::Ytype::try_from(y).map_err(|_| Error::OutOfBounds)?;
@@ -52,7 +55,7 @@ pub trait PosT: std::fmt::Debug + Default + Eq + PartialOrd + Copy {
     #[inline]
     fn into_tuple(self) -> (Self::Xtype, Self::Ytype)
     where
-        Self: std::marker::Sized,
+        Self: core::marker::Sized,
     {
         self.tuple()
     }
@@ -106,7 +109,7 @@ pub trait PosT: std::fmt::Debug + Default + Eq + PartialOrd + Copy {
         Self::Ytype: TryFrom<<P::Ytype as BoundedInt>::Inner>,
         <P::Xtype as BoundedInt>::Inner: BoundedInt,
         <P::Ytype as BoundedInt>::Inner: BoundedInt,
-        Self: std::marker::Sized,
+        Self: core::marker::Sized,
     {
         Self::new(pos.x().into_inner(), pos.y().into_inner())
     }
@@ -133,7 +136,7 @@ pub trait PosT: std::fmt::Debug + Default + Eq + PartialOrd + Copy {
     #[inline]
     fn first() -> Self
     where
-        Self: std::marker::Sized,
+        Self: core::marker::Sized,
     {
         Self::new_((Self::Xtype::MIN, Self::Ytype::MIN))
     }
@@ -142,7 +145,7 @@ pub trait PosT: std::fmt::Debug + Default + Eq + PartialOrd + Copy {
     #[inline]
     fn last() -> Self
     where
-        Self: std::marker::Sized,
+        Self: core::marker::Sized,
     {
         Self::new_((Self::Xtype::MAX, Self::Ytype::MAX))
     }
@@ -167,7 +170,7 @@ pub trait PosT: std::fmt::Debug + Default + Eq + PartialOrd + Copy {
     #[inline]
     fn flip_h(&self) -> Self
     where
-        Self: std::marker::Sized,
+        Self: core::marker::Sized,
     {
         Self::new(Self::Xtype::MAX.checked_sub(self.x()).unwrap(), self.y()).unwrap()
     }
@@ -176,7 +179,7 @@ pub trait PosT: std::fmt::Debug + Default + Eq + PartialOrd + Copy {
     #[inline]
     fn flip_v(&self) -> Self
     where
-        Self: std::marker::Sized,
+        Self: core::marker::Sized,
     {
         Self::new(self.x(), Self::Ytype::MAX.checked_sub(self.y()).unwrap()).unwrap()
     }
@@ -211,6 +214,46 @@ pub trait PosT: std::fmt::Debug + Default + Eq + PartialOrd + Copy {
         xmin <= self.x() && self.x() <= xmax && ymin <= self.y() && self.y() <= ymax
     }
 
+    /// Iterate over the neighbors of the position that are in the
+    /// grid, along with their directions
+    ///
+    /// `D` selects whether diagonal neighbors are included, as in
+    /// [`Dir::iter`].
+    fn neighbors<const D: bool>(&self) -> Neighbors<Self, D> {
+        Neighbors {
+            pos: *self,
+            dirs: Dir::iter::<D>(),
+        }
+    }
+
+    /// Iterate over the neighbors of the position, wrapping around
+    /// the edges of the grid, along with their directions
+    ///
+    /// `D` selects whether diagonal neighbors are included, as in
+    /// [`Dir::iter`].
+    fn neighbors_wrapping<const D: bool>(&self) -> NeighborsWrapping<Self, D> {
+        NeighborsWrapping {
+            pos: *self,
+            dirs: Dir::iter::<D>(),
+        }
+    }
+
+    /// Return the number of neighbors of the position that are in
+    /// the provided [`SetPos`]
+    ///
+    /// `D` selects whether diagonal neighbors are included, as in
+    /// [`Dir::iter`]; `WORDS` and `SIZE` are the parameters of the
+    /// set, that can be taken from the [`Sqrid`](super::base::Sqrid)
+    /// with [`Sqrid::count_neighbors_in`](super::base::Sqrid::count_neighbors_in).
+    fn count_neighbors_in<const D: bool, const WORDS: usize, const SIZE: usize>(
+        &self,
+        set: &impl SetPos<Self, WORDS, SIZE>,
+    ) -> usize {
+        self.neighbors::<D>()
+            .filter(|(_, pos)| set.contains(pos))
+            .count()
+    }
+
     /// Return a usize index corresponding to the position.
     #[inline]
     fn to_usize(&self) -> usize {
@@ -224,7 +267,7 @@ pub trait PosT: std::fmt::Debug + Default + Eq + PartialOrd + Copy {
     #[inline]
     fn tryfrom_usize(i: usize) -> Result<Self, Error>
     where
-        Self: std::marker::Sized,
+        Self: core::marker::Sized,
     {
         let width = Self::width();
         Self::new(i % width, i / width)
@@ -235,7 +278,7 @@ pub trait PosT: std::fmt::Debug + Default + Eq + PartialOrd + Copy {
     #[inline]
     fn next(&self) -> Option<Self>
     where
-        Self: std::marker::Sized,
+        Self: core::marker::Sized,
     {
         if let Some(x) = self.x().inc() {
             return Some(Self::new_((x, self.y())));
@@ -248,7 +291,7 @@ pub trait PosT: std::fmt::Debug + Default + Eq + PartialOrd + Copy {
     #[inline]
     fn next_y(&self) -> Option<Self>
     where
-        Self: std::marker::Sized,
+        Self: core::marker::Sized,
     {
         if let Some(y) = self.y().inc() {
             return Some(Self::new_((self.x(), y)));
@@ -261,7 +304,7 @@ pub trait PosT: std::fmt::Debug + Default + Eq + PartialOrd + Copy {
     #[inline]
     fn prev(&self) -> Option<Self>
     where
-        Self: std::marker::Sized,
+        Self: core::marker::Sized,
     {
         if let Some(x) = self.x().dec() {
             return Some(Self::new_((x, self.y())));
@@ -274,7 +317,7 @@ pub trait PosT: std::fmt::Debug + Default + Eq + PartialOrd + Copy {
     #[inline]
     fn prev_y(&self) -> Option<Self>
     where
-        Self: std::marker::Sized,
+        Self: core::marker::Sized,
     {
         if let Some(y) = self.y().dec() {
             return Some(Self::new_((self.x(), y)));
@@ -297,7 +340,7 @@ pub trait PosT: std::fmt::Debug + Default + Eq + PartialOrd + Copy {
     /// `false` for vertically.
     fn iter_orientation<const XFIRST: bool>() -> PosTIter<XFIRST, Self>
     where
-        Self: std::marker::Sized,
+        Self: core::marker::Sized,
     {
         PosTIter::<XFIRST, Self>::default()
     }
@@ -306,7 +349,7 @@ pub trait PosT: std::fmt::Debug + Default + Eq + PartialOrd + Copy {
     /// dimensions.
     fn iter() -> PosTIter<true, Self>
     where
-        Self: std::marker::Sized,
+        Self: core::marker::Sized,
     {
         Self::iter_orientation::<true>()
     }
@@ -315,7 +358,7 @@ pub trait PosT: std::fmt::Debug + Default + Eq + PartialOrd + Copy {
     /// dimensions horizontally.
     fn iter_horizontal() -> PosTIter<true, Self>
     where
-        Self: std::marker::Sized,
+        Self: core::marker::Sized,
     {
         Self::iter_orientation::<true>()
     }
@@ -324,7 +367,7 @@ pub trait PosT: std::fmt::Debug + Default + Eq + PartialOrd + Copy {
     /// dimensions vertically.
     fn iter_vertical() -> PosTIter<false, Self>
     where
-        Self: std::marker::Sized,
+        Self: core::marker::Sized,
     {
         Self::iter_orientation::<false>()
     }
@@ -333,7 +376,7 @@ pub trait PosT: std::fmt::Debug + Default + Eq + PartialOrd + Copy {
     /// coordinates.
     fn iter_range(topleft: Self, botright: Self) -> PosTIterRange<Self>
     where
-        Self: std::marker::Sized + Copy,
+        Self: core::marker::Sized + Copy,
     {
         PosTIterRange::<Self>::new(topleft, botright)
     }
@@ -341,7 +384,7 @@ pub trait PosT: std::fmt::Debug + Default + Eq + PartialOrd + Copy {
     /// Return an iterator that returns all positions in a column.
     fn iter_in_x(x: Self::Xtype) -> PosTIterInX<Self>
     where
-        Self: std::marker::Sized,
+        Self: core::marker::Sized,
     {
         PosTIterInX::<Self>(Some(Self::new_((x, Default::default()))))
     }
@@ -349,7 +392,7 @@ pub trait PosT: std::fmt::Debug + Default + Eq + PartialOrd + Copy {
     /// Return an iterator that returns all positions in a line.
     fn iter_in_y(y: Self::Ytype) -> PosTIterInY<Self>
     where
-        Self: std::marker::Sized,
+        Self: core::marker::Sized,
     {
         PosTIterInY::<Self>(Some(Self::new_((Default::default(), y))))
     }
@@ -357,7 +400,7 @@ pub trait PosT: std::fmt::Debug + Default + Eq + PartialOrd + Copy {
     /// Calculate a top-left and a bottom-right Pos's that contains all iterated points.
     fn tlbr_of(mut iter: impl Iterator<Item = Self>) -> Result<(Self, Self), Error>
     where
-        Self: std::marker::Sized,
+        Self: core::marker::Sized,
     {
         if let Some(firstpos) = iter.next() {
             let (tl_tuple, br_tuple) =
@@ -386,7 +429,7 @@ pub trait PosT: std::fmt::Debug + Default + Eq + PartialOrd + Copy {
         // We have to be able to subtract the inner types, and generate Xtype and Ytype from the
         // crossed inners.
         <<Self as PosT>::Ytype as BoundedInt>::Inner:
-            std::ops::Sub<Output = <<Self as PosT>::Ytype as BoundedInt>::Inner>,
+            core::ops::Sub<Output = <<Self as PosT>::Ytype as BoundedInt>::Inner>,
         Self::Xtype: TryFrom<<<Self as PosT>::Ytype as BoundedInt>::Inner>,
         Self::Ytype: TryFrom<<<Self as PosT>::Xtype as BoundedInt>::Inner>,
     {
@@ -406,7 +449,7 @@ pub trait PosT: std::fmt::Debug + Default + Eq + PartialOrd + Copy {
         // We have to be able to subtract the inner types, and generate Xtype and Ytype from the
         // crossed inners.
         <<Self as PosT>::Xtype as BoundedInt>::Inner:
-            std::ops::Sub<Output = <<Self as PosT>::Xtype as BoundedInt>::Inner>,
+            core::ops::Sub<Output = <<Self as PosT>::Xtype as BoundedInt>::Inner>,
         Self::Xtype: TryFrom<<<Self as PosT>::Ytype as BoundedInt>::Inner>,
         Self::Ytype: TryFrom<<<Self as PosT>::Xtype as BoundedInt>::Inner>,
     {
@@ -430,7 +473,7 @@ pub trait PosT: std::fmt::Debug + Default + Eq + PartialOrd + Copy {
 pub struct PosTIter<const XFIRST: bool, P> {
     cur: Option<P>,
     end: Option<P>,
-    p: std::marker::PhantomData<P>,
+    p: core::marker::PhantomData<P>,
 }
 
 impl<const XFIRST: bool, P: PosT> Default for PosTIter<XFIRST, P> {
@@ -438,7 +481,7 @@ impl<const XFIRST: bool, P: PosT> Default for PosTIter<XFIRST, P> {
         PosTIter {
             cur: Some(P::first()),
             end: Some(P::last()),
-            p: std::marker::PhantomData,
+            p: core::marker::PhantomData,
         }
     }
 }
@@ -517,8 +560,7 @@ impl<P: PosT> Iterator for PosTIterRange<P> {
                 if p.x() < self.topleft.x() {
                     pos = P::new(self.topleft.x(), p.y()).ok();
                 } else if p.x() > self.botright.x() {
-                    let y = p.y().inc()?;
-                    pos = P::new(self.topleft.x(), y).ok();
+                    pos = p.y().inc().and_then(|y| P::new(self.topleft.x(), y).ok());
                 }
             }
             self.value = pos.filter(|p| p.y() <= self.botright.y());
@@ -580,6 +622,59 @@ impl<P: PosT> Iterator for PosTIterInY<P> {
     }
 }
 
+/// Iterator over the neighbors of a position that are in the grid
+///
+/// Created by [`PosT::neighbors`].
+#[derive(Debug, Clone, Copy)]
+pub struct Neighbors<P: PosT, const D: bool> {
+    pos: P,
+    dirs: DirIter<D>,
+}
+
+impl<P: PosT, const D: bool> Iterator for Neighbors<P, D> {
+    type Item = (Dir, P);
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let tuple = self.pos.tuple();
+        self.dirs
+            .find_map(|dir| Some((dir, P::new_((tuple + dir).ok()?))))
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.dirs.size_hint().1)
+    }
+}
+
+/// Iterator over the neighbors of a position, wrapping around the
+/// edges of the grid
+///
+/// Created by [`PosT::neighbors_wrapping`].
+#[derive(Debug, Clone, Copy)]
+pub struct NeighborsWrapping<P: PosT, const D: bool> {
+    pos: P,
+    dirs: DirIter<D>,
+}
+
+impl<P: PosT, const D: bool> Iterator for NeighborsWrapping<P, D> {
+    type Item = (Dir, P);
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let dir = self.dirs.next()?;
+        let (x, y) = self.pos.tuple();
+        let (dx, dy) = <(i8, i8)>::from(dir);
+        let x = match dx {
+            1 => x.inc().unwrap_or(P::Xtype::MIN),
+            -1 => x.dec().unwrap_or(P::Xtype::MAX),
+            _ => x,
+        };
+        let y = match dy {
+            1 => y.inc().unwrap_or(P::Ytype::MIN),
+            -1 => y.dec().unwrap_or(P::Ytype::MAX),
+            _ => y,
+        };
+        Some((dir, P::new_((x, y))))
+    }
+}
+
 /* Implementations for standard unsigned tuples */
 
 macro_rules! postrait_integer_impl {
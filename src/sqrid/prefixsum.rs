@@ -0,0 +1,127 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+//! Summed-area tables, for constant-time rectangle sums
+//!
+//! [`Grid::prefix_sums`] builds a [`PrefixSums`] table in which each
+//! entry has the sum of all values above and to the left of a
+//! position. With it, [`PrefixSums::sum`] gets the sum of any
+//! rectangle with 4 lookups, regardless of its size, which makes
+//! queries like "densest k x k square" linear on the size of the grid.
+//! [`Gridbool::prefix_sums`] does the same for the number of `true`
+//! cells.
+//!
+//! Rectangles are given by their top-left and bottom-right corners,
+//! inclusive, as in [`PosT::iter_range`].
+//!
+//! Example usage:
+//!
+//! ```
+//! use sqrid::postrait::PosT;
+//!
+//! type Sqrid = sqrid::sqrid_create!(3, 3, false);
+//! type Pos = sqrid::pos_create!(Sqrid);
+//! type Grid = sqrid::grid_create!(Sqrid, u32);
+//!
+//! let grid = (0..16).collect::<Grid>();
+//! let sums = grid.prefix_sums();
+//! assert_eq!(sums.sum(&Pos::new(1, 1)?, &Pos::new(2, 2)?), 5 + 6 + 9 + 10);
+//! assert_eq!(sums.total(), (0..16).sum());
+//! // Densest 2x2 square:
+//! let best = Pos::iter_range(Pos::TOP_LEFT, Pos::new(2, 2)?)
+//!     .max_by_key(|pos| sums.sum(pos, &(*pos + sqrid::Dir::SE).unwrap()));
+//! assert_eq!(best, Some(Pos::new(2, 2)?));
+//! # Ok::<(), sqrid::Error>(())
+//! ```
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops;
+
+use super::grid::coord;
+use super::grid::Grid;
+use super::gridbool::Gridbool;
+use super::postrait::PosT;
+
+/// A summed-area table, that returns the sum of the values in any
+/// rectangle in constant time
+///
+/// Created by [`Grid::prefix_sums`] and [`Gridbool::prefix_sums`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrefixSums<T, P: PosT> {
+    /// Sums of the values above and to the left of each position,
+    /// with an extra row and column of zeros at the top and left
+    table: Vec<T>,
+    _pos: core::marker::PhantomData<P>,
+}
+
+impl<T, P: PosT> PrefixSums<T, P>
+where
+    T: ops::Add<Output = T> + ops::Sub<Output = T> + Copy + Default,
+{
+    /// Build the table from the values in row-major order
+    fn from_values(values: impl Iterator<Item = T>) -> Self {
+        let stride = P::width() + 1;
+        let mut table = vec![T::default(); stride * (P::height() + 1)];
+        for (i, value) in values.enumerate() {
+            let (x, y) = (i % P::width(), i / P::width());
+            let above = table[y * stride + x + 1];
+            let left = table[(y + 1) * stride + x];
+            let diagonal = table[y * stride + x];
+            // Adding before subtracting keeps unsigned types from
+            // underflowing:
+            table[(y + 1) * stride + x + 1] = value + above + left - diagonal;
+        }
+        PrefixSums {
+            table,
+            _pos: core::marker::PhantomData,
+        }
+    }
+
+    /// Return the sum of the values in the rectangle between
+    /// `topleft` and `botright`, inclusive
+    ///
+    /// Returns `T::default()` if `botright` is above or to the left of
+    /// `topleft`.
+    pub fn sum(&self, topleft: &P, botright: &P) -> T {
+        let (x1, y1) = topleft.tuple();
+        let (x2, y2) = botright.tuple();
+        if x2 < x1 || y2 < y1 {
+            return T::default();
+        }
+        let stride = P::width() + 1;
+        let (x1, y1) = (coord(x1), coord(y1));
+        let (x2, y2) = (coord(x2) + 1, coord(y2) + 1);
+        self.table[y2 * stride + x2] + self.table[y1 * stride + x1]
+            - self.table[y1 * stride + x2]
+            - self.table[y2 * stride + x1]
+    }
+
+    /// Return the sum of all values
+    pub fn total(&self) -> T {
+        self.table[self.table.len() - 1]
+    }
+}
+
+/* Grid and Gridbool constructors *********************************************/
+
+impl<T, P: PosT, const SIZE: usize> Grid<T, P, SIZE>
+where
+    T: ops::Add<Output = T> + ops::Sub<Output = T> + Copy + Default,
+{
+    /// Build the [`PrefixSums`] summed-area table of the grid
+    pub fn prefix_sums(&self) -> PrefixSums<T, P> {
+        PrefixSums::from_values(self.iter().copied())
+    }
+}
+
+impl<P: PosT, const WORDS: usize> Gridbool<P, WORDS> {
+    /// Build the [`PrefixSums`] table with the number of `true` cells
+    pub fn prefix_sums(&self) -> PrefixSums<usize, P> {
+        PrefixSums::from_values(self.iter().map(usize::from))
+    }
+}
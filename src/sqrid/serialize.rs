@@ -0,0 +1,171 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+//! [`serde`] support, enabled by the `serde` feature
+//!
+//! The types are serialized in a human-friendly way:
+//! - bounded integers as their inner value;
+//! - [`Pos`] as a `(x, y)` tuple;
+//! - [`Dir`] as its cardinal name, such as `"NE"`;
+//! - [`Grid`] as a sequence of rows;
+//! - [`Gridbool`] as a sequence of ASCII rows, with `#` for `true`
+//!   and `.` for `false`, as in its `Display` implementation.
+//!
+//! Deserialization checks the bounds of the values and the
+//! dimensions of the grids, failing with [`Error::OutOfBounds`].
+//!
+//! Example usage:
+//!
+//! ```
+//! use sqrid::postrait::PosT;
+//!
+//! type Pos = sqrid::Pos<3, 3>;
+//! type Gridbool = sqrid::gridbool_create!(Pos);
+//!
+//! let gb = Pos::iter().filter(|pos| pos.is_corner()).collect::<Gridbool>();
+//! let json = serde_json::to_string(&gb).unwrap();
+//! assert_eq!(json, r##"["#..#","....","....","#..#"]"##);
+//! assert_eq!(serde_json::from_str::<Gridbool>(&json).unwrap(), gb);
+//! ```
+
+use ::serde::de;
+use ::serde::Deserialize;
+use ::serde::Deserializer;
+use ::serde::Serialize;
+use ::serde::Serializer;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use super::boundedint::*;
+use super::dir::Dir;
+use super::error::Error;
+use super::grid::Grid;
+use super::gridbool::Gridbool;
+use super::pos::Pos;
+use super::postrait::PosT;
+
+/* Bounded integers */
+
+macro_rules! boundedint_serde_impl {
+    ($name:ident, $type:ty) => {
+        impl<const MIN: $type, const MAX: $type> Serialize for $name<MIN, MAX> {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                self.0.serialize(serializer)
+            }
+        }
+
+        impl<'de, const MIN: $type, const MAX: $type> Deserialize<'de> for $name<MIN, MAX> {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let value = <$type>::deserialize(deserializer)?;
+                Self::new(value).map_err(de::Error::custom)
+            }
+        }
+    };
+}
+
+boundedint_serde_impl!(BoundedU8, u8);
+boundedint_serde_impl!(BoundedU16, u16);
+boundedint_serde_impl!(BoundedU32, u32);
+boundedint_serde_impl!(BoundedU64, u64);
+boundedint_serde_impl!(BoundedU128, u128);
+boundedint_serde_impl!(BoundedIsize, isize);
+boundedint_serde_impl!(BoundedI8, i8);
+boundedint_serde_impl!(BoundedI16, i16);
+boundedint_serde_impl!(BoundedI32, i32);
+boundedint_serde_impl!(BoundedI64, i64);
+boundedint_serde_impl!(BoundedI128, i128);
+
+/* Pos */
+
+impl<const XMAX: u16, const YMAX: u16> Serialize for Pos<XMAX, YMAX> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.tuple().serialize(serializer)
+    }
+}
+
+impl<'de, const XMAX: u16, const YMAX: u16> Deserialize<'de> for Pos<XMAX, YMAX> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (x, y) = <(u16, u16)>::deserialize(deserializer)?;
+        Self::new(x, y).map_err(de::Error::custom)
+    }
+}
+
+/* Dir */
+
+impl Serialize for Dir {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name_cardinal())
+    }
+}
+
+impl<'de> Deserialize<'de> for Dir {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = <alloc::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+        Dir::ALL8
+            .into_iter()
+            .find(|dir| dir.name_cardinal() == name)
+            .ok_or_else(|| de::Error::custom(Error::DirParseError))
+    }
+}
+
+/* Grid */
+
+impl<T: Serialize, P: PosT, const SIZE: usize> Serialize for Grid<T, P, SIZE> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.as_array().chunks(P::width()))
+    }
+}
+
+impl<'de, T, P: PosT, const SIZE: usize> Deserialize<'de> for Grid<T, P, SIZE>
+where
+    T: Deserialize<'de> + Default,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let rows = Vec::<Vec<T>>::deserialize(deserializer)?;
+        if rows.len() != P::height() || rows.iter().any(|row| row.len() != P::width()) {
+            return Err(de::Error::custom(Error::OutOfBounds));
+        }
+        Self::try_from(rows).map_err(de::Error::custom)
+    }
+}
+
+/* Gridbool */
+
+impl<P: PosT, const WORDS: usize> Serialize for Gridbool<P, WORDS> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let bits = self.iter().collect::<Vec<_>>();
+        serializer.collect_seq(bits.chunks(P::width()).map(|row| {
+            row.iter()
+                .map(|&b| if b { '#' } else { '.' })
+                .collect::<String>()
+        }))
+    }
+}
+
+impl<'de, P: PosT, const WORDS: usize> Deserialize<'de> for Gridbool<P, WORDS> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let rows = Vec::<String>::deserialize(deserializer)?;
+        if rows.len() != P::height() || rows.iter().any(|row| row.chars().count() != P::width()) {
+            return Err(de::Error::custom(Error::OutOfBounds));
+        }
+        let mut gridbool = Self::default();
+        for (i, c) in rows.iter().flat_map(|row| row.chars()).enumerate() {
+            let value = match c {
+                '#' => true,
+                '.' => false,
+                _ => {
+                    return Err(de::Error::invalid_value(
+                        de::Unexpected::Char(c),
+                        &"'#' or '.'",
+                    ))
+                }
+            };
+            gridbool.set(&P::tryfrom_usize(i).map_err(de::Error::custom)?, value);
+        }
+        Ok(gridbool)
+    }
+}
@@ -7,7 +7,8 @@
 
 //! Module that abstracts sets of [`super::pos::Pos`] values
 
-use std::collections;
+use alloc::boxed::Box;
+use alloc::collections;
 
 use super::gridbool::Gridbool;
 use super::postrait::PosT;
@@ -45,9 +46,24 @@ impl<P: PosT, const WORDS: usize, const SIZE: usize> SetPos<P, WORDS, SIZE> for
 }
 
 impl<P: PosT, const WORDS: usize, const SIZE: usize> SetPos<P, WORDS, SIZE>
-    for collections::HashSet<P>
+    for Box<Gridbool<P, WORDS>>
+{
+    fn contains(&self, pos: &P) -> bool {
+        self.get(pos)
+    }
+    fn insert(&mut self, pos: P) {
+        self.set_t(&pos)
+    }
+    fn remove(&mut self, pos: &P) {
+        self.set_f(pos)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<P: PosT, const WORDS: usize, const SIZE: usize> SetPos<P, WORDS, SIZE>
+    for std::collections::HashSet<P>
 where
-    P: Eq + std::hash::Hash,
+    P: Eq + core::hash::Hash,
 {
     fn contains(&self, pos: &P) -> bool {
         self.contains(pos)
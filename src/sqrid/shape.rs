@@ -0,0 +1,295 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+//! Polyominoes and other piece shapes that can be placed on a [`Gridbool`]
+//!
+//! A [`Shape`] is a set of cells relative to the top-left corner of its bounding box, stored as
+//! a bitmap with one `u32` per row. That allows [`Shape::can_place`], [`Shape::place`] and
+//! [`Shape::remove`] to work on whole words of the [`Gridbool`] instead of on each position.
+//!
+//! Shapes implement [`Symmetric`], and [`Shape::orientations`] returns their unique rotations
+//! and reflections.
+//!
+//! Example usage:
+//!
+//! ```
+//! use sqrid::Dir;
+//! use sqrid::Shape;
+//!
+//! type Sqrid = sqrid::sqrid_create!(4, 6, false);
+//! type Pos = sqrid::pos_create!(Sqrid);
+//! type Gridbool = sqrid::gridbool_create!(Sqrid);
+//!
+//! // The "T" tetromino:
+//! let tee = Shape::new([(0, 0), (1, 0), (2, 0), (1, 1)])?;
+//! assert_eq!(tee.orientations().len(), 4);
+//!
+//! let mut gridbool = Gridbool::default();
+//! let pos = tee.drop_until_collision(&gridbool, &Pos::TOP_LEFT, Dir::S);
+//! assert_eq!(pos, Some(Pos::new(0, 5)?));
+//! tee.place(&mut gridbool, &pos.unwrap())?;
+//! assert!(!tee.can_place(&gridbool, &Pos::new(1, 5)?));
+//! # Ok::<(), sqrid::Error>(())
+//! ```
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+
+use super::postrait::PosT;
+use super::Dir;
+use super::Error;
+use super::Gridbool;
+use super::Pos;
+use super::Symmetric;
+use super::Symmetry;
+
+/// The maximum width and height of a [`Shape`]
+pub const SHAPE_MAX: u16 = 32;
+
+/// A set of cells, relative to the top-left corner of their
+/// bounding box
+///
+/// Each row is a `u32` bitmap with the leftmost cell in the most
+/// significant bit, which limits the width and height to
+/// [`SHAPE_MAX`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Shape {
+    width: u16,
+    rows: Vec<u32>,
+}
+
+impl Shape {
+    /// Create a shape from the coordinates of its cells
+    ///
+    /// The coordinates are normalized so that the top-left corner
+    /// of the bounding box is `(0, 0)`. Returns [`Error::Empty`] if
+    /// there are no cells, and [`Error::OutOfBounds`] if the shape
+    /// is larger than [`SHAPE_MAX`].
+    pub fn new(cells: impl IntoIterator<Item = (u16, u16)>) -> Result<Shape, Error> {
+        let cells = cells.into_iter().collect::<Vec<_>>();
+        let xmin = cells.iter().map(|c| c.0).min().ok_or(Error::Empty)?;
+        let ymin = cells.iter().map(|c| c.1).min().ok_or(Error::Empty)?;
+        let xmax = cells.iter().map(|c| c.0).max().ok_or(Error::Empty)?;
+        let ymax = cells.iter().map(|c| c.1).max().ok_or(Error::Empty)?;
+        if xmax - xmin >= SHAPE_MAX || ymax - ymin >= SHAPE_MAX {
+            return Err(Error::OutOfBounds);
+        }
+        let mut rows = vec![0; (ymax - ymin + 1) as usize];
+        for (x, y) in cells {
+            rows[(y - ymin) as usize] |= 0x80000000 >> (x - xmin);
+        }
+        Ok(Shape {
+            width: xmax - xmin + 1,
+            rows,
+        })
+    }
+
+    /// Width of the bounding box
+    #[inline]
+    pub fn width(&self) -> usize {
+        self.width as usize
+    }
+
+    /// Height of the bounding box
+    #[inline]
+    pub fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Number of cells in the shape
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.rows.iter().map(|row| row.count_ones() as usize).sum()
+    }
+
+    /// Shapes are never empty; provided for consistency with
+    /// [`Shape::len`]
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Return true if the provided cell is part of the shape
+    #[inline]
+    pub fn contains(&self, x: u16, y: u16) -> bool {
+        x < SHAPE_MAX
+            && self
+                .rows
+                .get(y as usize)
+                .is_some_and(|row| row & (0x80000000 >> x) != 0)
+    }
+
+    /// Iterate over the coordinates of the cells of the shape
+    pub fn cells(&self) -> impl Iterator<Item = (u16, u16)> + '_ {
+        (0..self.rows.len() as u16)
+            .flat_map(move |y| (0..self.width).map(move |x| (x, y)))
+            .filter(|&(x, y)| self.contains(x, y))
+    }
+
+    /// Return the unique rotations and reflections of the shape,
+    /// in the order of [`Symmetry::ALL`]
+    pub fn orientations(&self) -> Vec<Shape> {
+        let mut orientations = Vec::with_capacity(8);
+        for symmetry in Symmetry::ALL {
+            let shape = symmetry.apply(self);
+            if !orientations.contains(&shape) {
+                orientations.push(shape);
+            }
+        }
+        orientations
+    }
+
+    /// Return true if the shape fits inside the grid with its
+    /// top-left corner at `pos`
+    pub fn fits<P: PosT>(&self, pos: &P) -> bool {
+        let i = pos.to_usize();
+        i % P::width() + self.width() <= P::width() && i / P::width() + self.height() <= P::height()
+    }
+
+    /// Iterate over the index of the first word, and the masks for
+    /// it and for the next one, of each row of the shape placed at
+    /// `pos`
+    fn masks<P: PosT>(&self, pos: &P) -> impl Iterator<Item = (usize, u32, u32)> + '_ {
+        let start = pos.to_usize();
+        self.rows.iter().enumerate().map(move |(y, &row)| {
+            let bit = start + y * P::width();
+            let mask = ((row as u64) << 32) >> (bit % 32);
+            (bit / 32, (mask >> 32) as u32, mask as u32)
+        })
+    }
+
+    /// Return true if the shape fits inside the grid with its
+    /// top-left corner at `pos` and doesn't overlap any position
+    /// set in `gridbool`
+    pub fn can_place<P: PosT, const WORDS: usize>(
+        &self,
+        gridbool: &Gridbool<P, WORDS>,
+        pos: &P,
+    ) -> bool {
+        let words = gridbool.as_inner();
+        self.fits(pos)
+            && self
+                .masks(pos)
+                .all(|(i, hi, lo)| words[i] & hi == 0 && (lo == 0 || words[i + 1] & lo == 0))
+    }
+
+    /// Set the positions of the shape with its top-left corner at
+    /// `pos`
+    ///
+    /// Returns [`Error::OutOfBounds`] if the shape doesn't fit in
+    /// the grid, and [`Error::Collision`] if it overlaps positions
+    /// that are already set; `gridbool` is not changed in both
+    /// cases.
+    pub fn place<P: PosT, const WORDS: usize>(
+        &self,
+        gridbool: &mut Gridbool<P, WORDS>,
+        pos: &P,
+    ) -> Result<(), Error> {
+        if !self.fits(pos) {
+            return Err(Error::OutOfBounds);
+        }
+        if !self.can_place(gridbool, pos) {
+            return Err(Error::Collision);
+        }
+        let words = gridbool.as_inner_mut();
+        for (i, hi, lo) in self.masks(pos) {
+            words[i] |= hi;
+            if lo != 0 {
+                words[i + 1] |= lo;
+            }
+        }
+        Ok(())
+    }
+
+    /// Clear the positions of the shape with its top-left corner at
+    /// `pos`
+    ///
+    /// Returns [`Error::OutOfBounds`] if the shape doesn't fit in
+    /// the grid.
+    pub fn remove<P: PosT, const WORDS: usize>(
+        &self,
+        gridbool: &mut Gridbool<P, WORDS>,
+        pos: &P,
+    ) -> Result<(), Error> {
+        if !self.fits(pos) {
+            return Err(Error::OutOfBounds);
+        }
+        let words = gridbool.as_inner_mut();
+        for (i, hi, lo) in self.masks(pos) {
+            words[i] &= !hi;
+            if lo != 0 {
+                words[i + 1] &= !lo;
+            }
+        }
+        Ok(())
+    }
+
+    /// Move the shape from `pos` in the direction `dir` until it
+    /// collides with a set position or with the edge of the grid,
+    /// and return its last valid top-left position
+    ///
+    /// Returns `None` if the shape can't be placed at `pos` in the
+    /// first place.
+    pub fn drop_until_collision<P, const WORDS: usize>(
+        &self,
+        gridbool: &Gridbool<P, WORDS>,
+        pos: &P,
+        dir: Dir,
+    ) -> Option<P>
+    where
+        P: PosT + Copy,
+        P: core::ops::Add<Dir, Output = Result<P, Error>>,
+    {
+        if !self.can_place(gridbool, pos) {
+            return None;
+        }
+        let mut pos = *pos;
+        while let Ok(next) = pos + dir {
+            if !self.can_place(gridbool, &next) {
+                break;
+            }
+            pos = next;
+        }
+        Some(pos)
+    }
+}
+
+impl Symmetric for Shape {
+    fn transform(&self, symmetry: Symmetry) -> Self {
+        // Transform the cells in the largest square grid and let
+        // `new` normalize them back to the top-left corner:
+        type PosMax = Pos<{ SHAPE_MAX - 1 }, { SHAPE_MAX - 1 }>;
+        Shape::new(
+            self.cells()
+                .map(|(x, y)| symmetry.apply(&PosMax::new_unwrap(x, y)).tuple()),
+        )
+        .unwrap()
+    }
+}
+
+impl<P: PosT, const WORDS: usize> TryFrom<&Gridbool<P, WORDS>> for Shape {
+    type Error = Error;
+    fn try_from(gridbool: &Gridbool<P, WORDS>) -> Result<Self, Self::Error> {
+        Shape::new(gridbool.iter_t().map(|pos| {
+            let i = pos.to_usize();
+            ((i % P::width()) as u16, (i / P::width()) as u16)
+        }))
+    }
+}
+
+impl fmt::Display for Shape {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for y in 0..self.rows.len() as u16 {
+            for x in 0..self.width {
+                write!(f, "{}", if self.contains(x, y) { '#' } else { '.' })?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
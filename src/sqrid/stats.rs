@@ -0,0 +1,102 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+//! Search statistics and instrumentation hooks
+//!
+//! The search iterators - [`BfIterator`](crate::bf::BfIterator),
+//! [`AstarIterator`](crate::astar::AstarIterator) and
+//! [`UcsIterator`](crate::ucs::UcsIterator) - can optionally collect
+//! [`SearchStats`] and call a [`SearchObserver`] every time they expand
+//! a position, which allows us to tune movement functions and to
+//! visualize the expansion order without changing the iterators.
+//!
+//! Example usage:
+//!
+//! ```
+//! type Sqrid = sqrid::sqrid_create!(3, 3, false);
+//! type Pos = sqrid::pos_create!(Sqrid);
+//!
+//! let mut order = Vec::new();
+//! let mut it = Sqrid::bf_iter(sqrid::pos_dir_add_ok, &Pos::CENTER)
+//!     .with_stats()
+//!     .with_observer(|pos, distance| order.push((pos, distance)));
+//! for _ in &mut it {}
+//! let stats = it.stats().unwrap();
+//! assert_eq!(stats.expanded, Pos::SIZE);
+//! drop(it);
+//! assert_eq!(order[0], (Pos::CENTER, 0));
+//! ```
+
+use core::time::Duration;
+
+use super::ucs::Cost;
+
+/// Statistics collected by a search iterator
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SearchStats {
+    /// Number of positions expanded, i.e. whose neighbors were evaluated
+    pub expanded: usize,
+    /// Maximum number of entries in the frontier
+    pub max_frontier: usize,
+    /// Number of times a position was pushed into the frontier again
+    /// because a cheaper path to it was found
+    pub repushed: usize,
+    /// Time spent inside the iterator; always zero without the `std`
+    /// feature
+    pub elapsed: Duration,
+}
+
+/// Clock used to measure [`SearchStats::elapsed`], which is only
+/// available with the `std` feature
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Timer {
+    #[cfg(feature = "std")]
+    start: std::time::Instant,
+}
+
+impl Timer {
+    #[inline]
+    pub(crate) fn start() -> Timer {
+        Timer {
+            #[cfg(feature = "std")]
+            start: std::time::Instant::now(),
+        }
+    }
+
+    #[inline]
+    pub(crate) fn elapsed(&self) -> Duration {
+        #[cfg(feature = "std")]
+        return self.start.elapsed();
+        #[cfg(not(feature = "std"))]
+        return Duration::ZERO;
+    }
+}
+
+/// Observer called by the search iterators when they expand a position
+///
+/// It's implemented for `()`, which does nothing, and for closures
+/// that take the position and its cost - which is the distance from
+/// the origin for breadth-first iterators.
+pub trait SearchObserver<P> {
+    /// Called before the neighbors of `pos` are evaluated
+    fn on_expand(&mut self, pos: P, cost: Cost);
+}
+
+impl<P> SearchObserver<P> for () {
+    #[inline]
+    fn on_expand(&mut self, _pos: P, _cost: Cost) {}
+}
+
+impl<P, F> SearchObserver<P> for F
+where
+    F: FnMut(P, Cost),
+{
+    #[inline]
+    fn on_expand(&mut self, pos: P, cost: Cost) {
+        self(pos, cost)
+    }
+}
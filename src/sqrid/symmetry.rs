@@ -0,0 +1,201 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+//! Symmetries of the square
+//!
+//! The [`Symmetry`] enum has the 8 elements of the dihedral group D4: the rotations by
+//! multiples of 90 degrees and the reflections of the square. They can be composed, inverted,
+//! and applied to the types that implement the [`Symmetric`] trait: [`Dir`], and the square
+//! [`Pos`], [`Grid`] and [`Gridbool`].
+//!
+//! [`Symmetric::canonical_form`] returns the smallest of the 8 transformations of a value,
+//! which is the same for all values that are symmetries of each other - useful to deduplicate
+//! puzzle states.
+//!
+//! Example usage:
+//!
+//! ```
+//! use sqrid::Symmetric;
+//! use sqrid::Symmetry;
+//!
+//! type Sqrid = sqrid::sqrid_create!(2, 2, false);
+//! type Pos = sqrid::pos_create!(Sqrid);
+//! type Grid = sqrid::grid_create!(Sqrid, u8);
+//!
+//! let pos = Pos::TOP_LEFT;
+//! assert_eq!(Symmetry::RotateCw.apply(&pos), Pos::TOP_RIGHT);
+//! assert_eq!(Symmetry::Transpose.apply(&sqrid::Dir::E), sqrid::Dir::S);
+//!
+//! let mut grid = Grid::default();
+//! grid[Pos::TOP_LEFT] = 1;
+//! let (canonical, symmetry) = grid.canonical_form();
+//! assert_eq!(canonical[Pos::BOTTOM_RIGHT], 1);
+//! assert_eq!(symmetry.inverse().apply(&canonical), grid);
+//! ```
+
+use super::postrait::PosT;
+use super::Dir;
+use super::Grid;
+use super::Gridbool;
+use super::Pos;
+
+/// The 8 symmetries of the square
+///
+/// Each element is equivalent to an optional horizontal flip
+/// followed by 0 to 3 clockwise rotations, in the order of the
+/// discriminants.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Symmetry {
+    /// No change
+    #[default]
+    Identity = 0,
+    /// Rotate 90 degrees clockwise
+    RotateCw,
+    /// Rotate 180 degrees
+    Rotate180,
+    /// Rotate 90 degrees counterclockwise
+    RotateCc,
+    /// Flip horizontally, exchanging left and right
+    FlipH,
+    /// Reflect over the anti-diagonal, from the top-right to the
+    /// bottom-left
+    AntiTranspose,
+    /// Flip vertically, exchanging top and bottom
+    FlipV,
+    /// Reflect over the main diagonal, exchanging x and y
+    Transpose,
+}
+
+impl Symmetry {
+    /// All symmetries, in discriminant order
+    pub const ALL: [Symmetry; 8] = [
+        Symmetry::Identity,
+        Symmetry::RotateCw,
+        Symmetry::Rotate180,
+        Symmetry::RotateCc,
+        Symmetry::FlipH,
+        Symmetry::AntiTranspose,
+        Symmetry::FlipV,
+        Symmetry::Transpose,
+    ];
+
+    /// Create the symmetry that flips horizontally if `flip` is
+    /// true, and then rotates clockwise `rotations` times
+    #[inline]
+    pub const fn new(flip: bool, rotations: usize) -> Symmetry {
+        Symmetry::ALL[(flip as usize) * 4 + rotations % 4]
+    }
+
+    /// Return true if the symmetry includes a horizontal flip;
+    /// i.e. if it's a reflection
+    #[inline]
+    pub const fn flips(&self) -> bool {
+        (*self as usize) >= 4
+    }
+
+    /// Return the number of clockwise rotations applied after the
+    /// optional flip
+    #[inline]
+    pub const fn rotations(&self) -> usize {
+        (*self as usize) % 4
+    }
+
+    /// Return the symmetry equivalent to applying `self` and then
+    /// `other`
+    #[inline]
+    pub const fn compose(&self, other: &Symmetry) -> Symmetry {
+        // Flipping and then rotating clockwise is the same as
+        // rotating counterclockwise and then flipping:
+        let rotations = if other.flips() {
+            other.rotations() + 4 - self.rotations()
+        } else {
+            other.rotations() + self.rotations()
+        };
+        Symmetry::new(self.flips() != other.flips(), rotations)
+    }
+
+    /// Return the symmetry that undoes `self`
+    #[inline]
+    pub const fn inverse(&self) -> Symmetry {
+        if self.flips() {
+            // Reflections are their own inverse
+            *self
+        } else {
+            Symmetry::new(false, 4 - self.rotations())
+        }
+    }
+
+    /// Apply the symmetry to the provided value; see [`Symmetric`]
+    #[inline]
+    pub fn apply<S: Symmetric>(&self, value: &S) -> S {
+        value.transform(*self)
+    }
+}
+
+/// Types that can be transformed by the symmetries of the square
+pub trait Symmetric {
+    /// Return the value transformed by the provided [`Symmetry`]
+    fn transform(&self, symmetry: Symmetry) -> Self;
+
+    /// Return the smallest of the transformations of the value,
+    /// along with the symmetry that generates it
+    ///
+    /// All values that are symmetries of each other have the same
+    /// canonical form.
+    fn canonical_form(&self) -> (Self, Symmetry)
+    where
+        Self: Sized + Ord,
+    {
+        Symmetry::ALL
+            .iter()
+            .map(|symmetry| (self.transform(*symmetry), *symmetry))
+            .min()
+            .unwrap()
+    }
+}
+
+impl Symmetric for Dir {
+    fn transform(&self, symmetry: Symmetry) -> Self {
+        let mut index = *self as usize;
+        if symmetry.flips() {
+            index = (Dir::SIZE - index) % Dir::SIZE;
+        }
+        Dir::ALL8[(index + 2 * symmetry.rotations()) % Dir::SIZE]
+    }
+}
+
+impl<const W: u16> Symmetric for Pos<W, W> {
+    fn transform(&self, symmetry: Symmetry) -> Self {
+        let mut pos = if symmetry.flips() {
+            self.flip_h()
+        } else {
+            *self
+        };
+        for _ in 0..symmetry.rotations() {
+            pos = pos.rotate_cw();
+        }
+        pos
+    }
+}
+
+impl<T: Clone, const W: u16, const SIZE: usize> Symmetric for Grid<T, Pos<W, W>, SIZE> {
+    fn transform(&self, symmetry: Symmetry) -> Self {
+        let mut grid = self.clone();
+        for pos in Pos::<W, W>::iter() {
+            grid[pos.transform(symmetry)] = self[pos].clone();
+        }
+        grid
+    }
+}
+
+impl<const W: u16, const WORDS: usize> Symmetric for Gridbool<Pos<W, W>, WORDS> {
+    fn transform(&self, symmetry: Symmetry) -> Self {
+        let mut gridbool = Self::ALL_FALSE;
+        gridbool.set_iter_t(self.iter_t().map(|pos| pos.transform(symmetry)));
+        gridbool
+    }
+}
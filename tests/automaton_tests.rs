@@ -0,0 +1,153 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use sqrid::automaton::Edges;
+use sqrid::postrait::PosT;
+
+use anyhow::Result;
+
+fn lcg(mut seed: u64) -> impl FnMut() -> bool {
+    move || {
+        seed = seed
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (seed >> 33).is_multiple_of(3)
+    }
+}
+
+fn life(alive: &bool, neighbors: &[Option<&bool>]) -> bool {
+    let count = neighbors.iter().filter(|&&n| n == Some(&true)).count();
+    count == 3 || (*alive && count == 2)
+}
+
+macro_rules! check_life {
+    ($xmax:expr, $ymax:expr) => {{
+        type Sqrid = sqrid::sqrid_create!($xmax, $ymax, false);
+        type Pos = sqrid::pos_create!(Sqrid);
+        type Grid = sqrid::grid_create!(Sqrid, bool);
+        type Gridbool = sqrid::gridbool_create!(Sqrid);
+        for edges in [Edges::Bounded, Edges::Toroidal] {
+            for seed in 0..3 {
+                let mut rng = lcg(seed);
+                let mut grid = Grid::default();
+                for pos in Pos::iter() {
+                    grid[pos] = rng();
+                }
+                let mut gridbool = Pos::iter().map(|pos| grid[pos]).collect::<Gridbool>();
+                for _ in 0..10 {
+                    let changed1 = grid.step_automaton::<true>(edges, life);
+                    let changed2 = gridbool.step_life(edges);
+                    assert_eq!(changed1, changed2);
+                    for pos in Pos::iter() {
+                        assert_eq!(grid[pos], gridbool[pos], "{:?} {:?}", edges, pos);
+                    }
+                }
+            }
+        }
+    }};
+}
+
+#[test]
+fn test_life_bitparallel() {
+    check_life!(4, 4);
+    check_life!(30, 6);
+    check_life!(31, 3);
+    check_life!(63, 2);
+    check_life!(64, 3);
+    check_life!(69, 4);
+    check_life!(130, 2);
+}
+
+type Sqrid = sqrid::sqrid_create!(7, 7, false);
+type Pos = sqrid::pos_create!(Sqrid);
+type Grid = sqrid::grid_create!(Sqrid, u8);
+type Gridbool = sqrid::gridbool_create!(Sqrid);
+
+#[test]
+fn test_glider_toroidal() -> Result<()> {
+    let glider = [(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)];
+    let mut gridbool = Gridbool::default();
+    for (x, y) in glider {
+        gridbool.set_t(&Pos::new(x, y)?);
+    }
+    // A glider moves 1 position diagonally every 4 steps, and
+    // wraps around a 8x8 toroidal grid after 32 steps:
+    let start = gridbool;
+    for _ in 0..4 {
+        assert!(gridbool.step_life(Edges::Toroidal));
+    }
+    for (x, y) in glider {
+        assert!(gridbool[Pos::new(x + 1, y + 1)?]);
+    }
+    assert_eq!(gridbool.iter_t().count(), glider.len());
+    for _ in 4..32 {
+        gridbool.step_life(Edges::Toroidal);
+    }
+    assert_eq!(gridbool, start);
+    // In a bounded grid, it becomes a block:
+    assert_eq!(gridbool.run_life(Edges::Bounded, 100), Some(23));
+    assert_eq!(gridbool.iter_t().count(), 4);
+    Ok(())
+}
+
+#[test]
+fn test_run_life() {
+    // Still life and oscillators:
+    let mut gridbool = Gridbool::default();
+    assert_eq!(gridbool.run_life(Edges::Bounded, 10), Some(0));
+    gridbool = Gridbool::ALL_TRUE;
+    assert_eq!(gridbool.run_life(Edges::Toroidal, 10), Some(1));
+    assert_eq!(gridbool, Gridbool::ALL_FALSE);
+    let mut blinker = Gridbool::default();
+    blinker.set_iter_t(
+        [(1, 2), (2, 2), (3, 2)]
+            .iter()
+            .map(|&xy| Pos::try_from(xy).unwrap()),
+    );
+    assert_eq!(blinker.run_life(Edges::Bounded, 10), None);
+}
+
+#[test]
+fn test_automaton() -> Result<()> {
+    // Distance to the nearest source, as a fixed point:
+    let mut grid = Grid::repeat(u8::MAX);
+    grid[Pos::TOP_LEFT] = 0;
+    let distance = |value: &u8, neighbors: &[Option<&u8>]| {
+        neighbors
+            .iter()
+            .flatten()
+            .map(|n| n.saturating_add(1))
+            .fold(*value, u8::min)
+    };
+    let steps = grid.run_automaton::<false>(Edges::Bounded, 100, distance);
+    assert_eq!(steps, Some(14));
+    for pos in Pos::iter() {
+        assert_eq!(grid[pos] as usize, pos.manhattan(&Pos::TOP_LEFT));
+    }
+    // With diagonals and wrapping around:
+    let mut grid = Grid::repeat(u8::MAX);
+    grid[Pos::TOP_LEFT] = 0;
+    grid.run_automaton::<true>(Edges::Toroidal, 100, distance);
+    assert_eq!(grid[Pos::BOTTOM_RIGHT], 1);
+    assert_eq!(grid[Pos::new(4, 4)?], 4);
+    assert_eq!(grid[Pos::new(3, 5)?], 3);
+    // Neighbors outside of bounded grids are None:
+    let mut grid = Grid::default();
+    grid.step_automaton::<true>(Edges::Bounded, |_, neighbors| {
+        neighbors.iter().flatten().count() as u8
+    });
+    assert_eq!(grid[Pos::TOP_LEFT], 3);
+    assert_eq!(grid[Pos::new(0, 3)?], 5);
+    assert_eq!(grid[Pos::new(3, 3)?], 8);
+    // Alternating between two buffers:
+    let mut grid = Grid::repeat(u8::MAX);
+    grid[Pos::TOP_LEFT] = 0;
+    let mut next = Grid::default();
+    while grid.step_automaton_into::<false>(&mut next, Edges::Bounded, distance) {
+        std::mem::swap(&mut grid, &mut next);
+    }
+    assert_eq!(next, grid);
+    assert_eq!(grid[Pos::BOTTOM_RIGHT], 14);
+    Ok(())
+}
@@ -0,0 +1,95 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use sqrid::automaton::Edges;
+use sqrid::Cycle;
+
+use anyhow::Result;
+
+type Sqrid = sqrid::sqrid_create!(5, 5, false);
+type Pos = sqrid::pos_create!(Sqrid);
+type Gridbool = sqrid::gridbool_create!(Sqrid);
+
+fn brute_force<T: Clone>(initial: &T, step: impl Fn(&T) -> T, n: usize) -> T {
+    (0..n).fold(initial.clone(), |state, _| step(&state))
+}
+
+#[test]
+fn test_numbers() {
+    for modulo in [2_u64, 7, 255, 1000, 4099] {
+        for start in [0_u64, 1, 3, 99] {
+            let step = |x: &u64| (x * x + 1) % modulo;
+            let cycle = sqrid::find_cycle_brent(&start, step);
+            #[cfg(feature = "std")]
+            assert_eq!(sqrid::find_cycle(&start, step), cycle);
+            assert!(cycle.length > 0);
+            for n in [0, 1, 5, 17, 1000, 12345] {
+                assert_eq!(
+                    cycle.state_at(&start, step, n),
+                    brute_force(&start, step, n)
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_index() {
+    let cycle = Cycle {
+        prefix: 3,
+        length: 4,
+    };
+    assert_eq!(cycle.index(2), 2);
+    assert_eq!(cycle.index(3), 3);
+    assert_eq!(cycle.index(7), 3);
+    assert_eq!(cycle.index(10), 6);
+    assert_eq!(cycle.index(1_000_000_000), 3 + (1_000_000_000 - 3) % 4);
+}
+
+#[test]
+fn test_life() -> Result<()> {
+    let step = |g: &Gridbool| {
+        let mut g = *g;
+        g.step_life(Edges::Toroidal);
+        g
+    };
+    // Blinker, period 2:
+    let mut blinker = Gridbool::default();
+    for x in 1..4 {
+        blinker.set_t(&Pos::new(x, 2)?);
+    }
+    let cycle = sqrid::find_cycle_brent(&blinker, step);
+    assert_eq!((cycle.prefix, cycle.length), (0, 2));
+    #[cfg(feature = "std")]
+    assert_eq!(sqrid::find_cycle(&blinker, step), cycle);
+    // Glider in a 6x6 torus, that moves back to the same position
+    // after 24 steps:
+    let mut glider = Gridbool::default();
+    for (x, y) in [(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)] {
+        glider.set_t(&Pos::new(x, y)?);
+    }
+    let cycle = sqrid::find_cycle_brent(&glider, step);
+    assert_eq!((cycle.prefix, cycle.length), (0, 24));
+    #[cfg(feature = "std")]
+    assert_eq!(sqrid::find_cycle(&glider, step), cycle);
+    // Pattern that stabilizes after a few steps:
+    let mut pattern = Gridbool::default();
+    for (x, y) in [(0, 0), (1, 0), (2, 0), (2, 1)] {
+        pattern.set_t(&Pos::new(x, y)?);
+    }
+    let cycle = sqrid::find_cycle_brent(&pattern, step);
+    assert!(cycle.prefix > 0);
+    #[cfg(feature = "std")]
+    assert_eq!(sqrid::find_cycle(&pattern, step), cycle);
+    let state = cycle.state_at(&pattern, step, 1_000_000_000);
+    assert_eq!(
+        state,
+        brute_force(&pattern, step, cycle.index(1_000_000_000))
+    );
+    assert_eq!(
+        cycle.state_at(&pattern, step, 100),
+        brute_force(&pattern, step, 100)
+    );
+    Ok(())
+}
@@ -7,6 +7,7 @@ use std::hash::{Hash, Hasher};
 
 use sqrid;
 use sqrid::postrait::PosT;
+use sqrid::Symmetry;
 
 use anyhow::anyhow;
 use anyhow::Result;
@@ -299,3 +300,313 @@ fn test_rotate_cc() -> Result<()> {
     );
     Ok(())
 }
+
+type Pos10 = sqrid::Pos<9, 9>;
+type GridChar10 = sqrid::grid_create!(Pos10, char);
+
+fn gridchar10(lines: &[&str]) -> Result<GridChar10> {
+    let mut grid = GridChar10::repeat('.');
+    grid.extend_from_vecvec(lines.iter().map(|l| l.chars().collect()).collect())?;
+    Ok(grid)
+}
+
+fn slide(grid: &mut GridChar10, dir: sqrid::Dir) -> usize {
+    grid.slide(dir, |&c| c == 'O', |&c| c == '#')
+}
+
+#[test]
+fn test_slide() -> Result<()> {
+    let start = gridchar10(&[
+        "O....#....",
+        "O.OO#....#",
+        ".....##...",
+        "OO.#O....O",
+        ".O.....O#.",
+        "O.#..O.#.#",
+        "..O..#O..O",
+        ".......O..",
+        "#....###..",
+        "#OO..#....",
+    ])?;
+    let mut grid = start;
+    assert_eq!(slide(&mut grid, sqrid::Dir::N), 14);
+    assert_eq!(
+        grid,
+        gridchar10(&[
+            "OOOO.#.O..",
+            "OO..#....#",
+            "OO..O##..O",
+            "O..#.OO...",
+            "........#.",
+            "..#....#.#",
+            "..O..#.O.O",
+            "..O.......",
+            "#....###..",
+            "#....#....",
+        ])?
+    );
+    // Sliding again moves nothing:
+    assert_eq!(slide(&mut grid, sqrid::Dir::N), 0);
+    // The number of items is kept in all directions:
+    let count = |grid: &GridChar10| grid.iter().filter(|&&c| c == 'O').count();
+    for dir in sqrid::Dir::iter::<true>() {
+        let mut grid = start;
+        slide(&mut grid, dir);
+        assert_eq!(count(&grid), count(&start));
+        assert_eq!(slide(&mut grid, dir), 0, "{}", dir);
+    }
+    // Horizontally:
+    let mut grid = gridchar10(&["O.O.#..O.O"])?;
+    assert_eq!(slide(&mut grid, sqrid::Dir::E), 3);
+    assert_eq!(
+        grid.line(0.try_into()?).iter().collect::<String>(),
+        "..OO#...OO"
+    );
+    assert_eq!(slide(&mut grid, sqrid::Dir::W), 4);
+    assert_eq!(
+        grid.line(0.try_into()?).iter().collect::<String>(),
+        "OO..#OO..."
+    );
+    // Diagonally:
+    let mut grid = gridchar10(&["..........", "..........", "..O......."])?;
+    assert_eq!(slide(&mut grid, sqrid::Dir::NE), 1);
+    assert_eq!(grid[Pos10::new(4, 0)?], 'O');
+    assert_eq!(slide(&mut grid, sqrid::Dir::SW), 1);
+    assert_eq!(grid[Pos10::new(0, 4)?], 'O');
+    assert_eq!(slide(&mut grid, sqrid::Dir::SE), 1);
+    assert_eq!(grid[Pos10::new(5, 9)?], 'O');
+    Ok(())
+}
+
+#[test]
+fn test_find_pattern() -> Result<()> {
+    let grid = gridchar10(&[
+        "..........",
+        ".#.#......",
+        "..#.......",
+        ".#.#...#.#",
+        "........#.",
+        ".......#.#",
+        "..........",
+        "####......",
+        "..........",
+        ".........#",
+    ])?;
+    type PosX = sqrid::Pos<2, 2>;
+    type GridX = sqrid::grid_create!(PosX, char);
+    let mut cross = GridX::repeat('?');
+    cross.extend_from_vecvec(vec![
+        vec!['#', '?', '#'],
+        vec!['?', '#', '?'],
+        vec!['#', '?', '#'],
+    ])?;
+    assert_eq!(
+        grid.find_pattern(&cross, Some(&'?')),
+        vec![Pos10::new(1, 1)?, Pos10::new(7, 3)?]
+    );
+    // Without the wildcard, it only matches literally:
+    assert_eq!(grid.find_pattern(&cross, None), vec![]);
+    // Non-square patterns, at the edges:
+    type Pos4x1 = sqrid::Pos<3, 0>;
+    let line = <sqrid::grid_create!(Pos4x1, char)>::repeat('#');
+    assert_eq!(grid.find_pattern(&line, None), vec![Pos10::new(0, 7)?]);
+    type Pos1x1 = sqrid::Pos<0, 0>;
+    let dot = <sqrid::grid_create!(Pos1x1, char)>::repeat('#');
+    assert_eq!(grid.find_pattern(&dot, None).len(), 15);
+    assert_eq!(
+        grid.find_pattern(&dot, None).last(),
+        Some(&Pos10::new(9, 9)?)
+    );
+    // Patterns larger than the grid never match:
+    let big = <sqrid::grid_create!(sqrid::Pos<10, 0>, char)>::repeat('.');
+    assert_eq!(grid.find_pattern(&big, None), vec![]);
+    Ok(())
+}
+
+#[test]
+fn test_find_pattern_symmetries() -> Result<()> {
+    let grid = gridchar10(&[
+        "##........",
+        "#.........",
+        "......##..",
+        ".......#..",
+        "........#.",
+        "..#.......",
+        "..##......",
+        "........#.",
+        ".......##.",
+        "..........",
+    ])?;
+    type PosL = sqrid::Pos<1, 1>;
+    type GridL = sqrid::grid_create!(PosL, char);
+    let mut ell = GridL::repeat('.');
+    ell.extend_from_vecvec(vec![vec!['#', '.'], vec!['#', '#']])?;
+    assert_eq!(
+        grid.find_pattern_symmetries(&ell, Some(&'.')),
+        vec![
+            (Symmetry::Identity, Pos10::new(2, 5)?),
+            (Symmetry::RotateCw, Pos10::new(0, 0)?),
+            (Symmetry::Rotate180, Pos10::new(6, 2)?),
+            (Symmetry::RotateCc, Pos10::new(7, 7)?),
+        ]
+    );
+    // Symmetric patterns are only matched once:
+    let block = GridL::repeat('#');
+    assert_eq!(grid.find_pattern_symmetries(&block, None), vec![]);
+    let mut diagonal = GridL::repeat('.');
+    diagonal.extend_from_vecvec(vec![vec!['#', '.'], vec!['.', '#']])?;
+    assert_eq!(
+        grid.find_pattern_symmetries(&diagonal, None),
+        vec![(Symmetry::Identity, Pos10::new(7, 3)?)]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_neighbor_values() -> Result<()> {
+    let grid = (0..15).collect::<Grid>();
+    let pos = Pos::try_from((4, 1))?;
+    assert_eq!(
+        grid.neighbor_values::<false>(&pos)
+            .map(|(dir, neighbor, &value)| (dir, neighbor, value))
+            .collect::<Vec<_>>(),
+        vec![
+            (sqrid::Dir::N, Pos::try_from((4, 0))?, 4),
+            (sqrid::Dir::S, Pos::try_from((4, 2))?, 14),
+            (sqrid::Dir::W, Pos::try_from((3, 1))?, 8),
+        ]
+    );
+    let sum = grid
+        .neighbor_values::<true>(&Pos::CENTER)
+        .map(|(_, _, value)| value)
+        .sum::<i32>();
+    assert_eq!(sum, 8 * 7);
+    Ok(())
+}
+
+type PosBig = sqrid::Pos<999, 999>;
+type GridBig = sqrid::grid_create!(PosBig, usize);
+
+#[test]
+fn test_new_boxed() -> Result<()> {
+    assert_eq!(*Grid::new_boxed(7), Grid::repeat(7));
+    assert_eq!(*Grid::default_boxed(), Grid::default());
+    // 8MB, larger than the stack of the test threads:
+    const _: () = assert!(!Grid::SHOULD_BOX && GridBig::SHOULD_BOX);
+    let mut grid = GridBig::new_boxed(3);
+    grid[PosBig::LAST] = 5;
+    assert_eq!(grid.iter().sum::<usize>(), 3 * GridBig::SIZE + 2);
+    let mut map = <Box<GridBig> as sqrid::MapPos<usize, PosBig, 0, { GridBig::SIZE }>>::new(1);
+    sqrid::MapPos::<usize, PosBig, 0, { GridBig::SIZE }>::set(&mut map, PosBig::FIRST, 2);
+    assert_eq!(map[PosBig::FIRST], 2);
+    assert_eq!(map[PosBig::LAST], 1);
+    Ok(())
+}
+
+#[test]
+fn test_from_fn() -> Result<()> {
+    let grid = Grid::from_fn(|pos| pos.to_usize() as i32);
+    assert_eq!(grid, (0..15).collect::<Grid>());
+    let grid = Grid::from_fn(|pos| pos.x() as i32 * 10 + pos.y() as i32);
+    assert_eq!(grid[Pos::try_from((3, 2))?], 32);
+    // try_from_fn stops at the first error:
+    let mut calls = 0;
+    let result = Grid::try_from_fn(|pos| {
+        calls += 1;
+        if pos == Pos::CENTER {
+            Err(anyhow!("center"))
+        } else {
+            Ok(1)
+        }
+    });
+    assert_eq!(result.unwrap_err().to_string(), "center");
+    assert_eq!(calls, Pos::CENTER.to_usize() + 1);
+    let grid = Grid::try_from_fn(|pos| i32::try_from(pos.to_usize()))?;
+    assert_eq!(grid, (0..15).collect::<Grid>());
+    Ok(())
+}
+
+#[test]
+fn test_map_zip() -> Result<()> {
+    let grid = (0..15).collect::<Grid>();
+    let doubled = grid.map(|v| v * 2);
+    assert_eq!(doubled, (0..15).map(|v| v * 2).collect::<Grid>());
+    let strings: sqrid::Grid<String, Pos, 15> = grid.map(|v| v.to_string());
+    assert_eq!(strings[Pos::LAST], "14");
+    let mapped = grid.map_pos(|pos, &v| (pos, v));
+    for pos in Pos::iter() {
+        assert_eq!(mapped[pos], (pos, grid[pos]));
+    }
+    let zipped = grid.zip(&strings);
+    assert_eq!(zipped[Pos::CENTER], (7, "7".to_string()));
+    let sum = grid.zip_with(&doubled, |a, b| a + b);
+    assert_eq!(sum, grid.map(|v| v * 3));
+    Ok(())
+}
+
+#[test]
+fn test_rows_columns() -> Result<()> {
+    let mut grid = (0..15).collect::<Grid>();
+    assert_eq!(
+        grid.rows().collect::<Vec<_>>(),
+        vec![[0, 1, 2, 3, 4], [5, 6, 7, 8, 9], [10, 11, 12, 13, 14]]
+    );
+    assert_eq!(
+        grid.column(1.try_into()?).copied().collect::<Vec<_>>(),
+        vec![1, 6, 11]
+    );
+    assert_eq!(
+        grid.columns()
+            .map(|column| column.sum::<i32>())
+            .collect::<Vec<_>>(),
+        vec![15, 18, 21, 24, 27]
+    );
+    for row in grid.rows_mut() {
+        row[0] = -1;
+    }
+    for value in grid.column_mut(4.try_into()?) {
+        *value = -2;
+    }
+    assert_eq!(grid.line(1.try_into()?), [-1, 6, 7, 8, -2]);
+    Ok(())
+}
+
+#[test]
+fn test_swap_rotate_lines() -> Result<()> {
+    let mut grid = (0..15).collect::<Grid>();
+    grid.swap_rows(0.try_into()?, 2.try_into()?);
+    assert_eq!(
+        grid.as_ref(),
+        &[10, 11, 12, 13, 14, 5, 6, 7, 8, 9, 0, 1, 2, 3, 4]
+    );
+    grid.swap_columns(0.try_into()?, 3.try_into()?);
+    assert_eq!(
+        grid.as_ref(),
+        &[13, 11, 12, 10, 14, 8, 6, 7, 5, 9, 3, 1, 2, 0, 4]
+    );
+    let mut grid = (0..15).collect::<Grid>();
+    grid.rotate_row(1.try_into()?, 2);
+    assert_eq!(grid.line(1.try_into()?), [8, 9, 5, 6, 7]);
+    grid.rotate_row(1.try_into()?, -7);
+    assert_eq!(grid.line(1.try_into()?), [5, 6, 7, 8, 9]);
+    grid.rotate_column(2.try_into()?, 1);
+    assert_eq!(
+        grid.column(2.try_into()?).copied().collect::<Vec<_>>(),
+        vec![12, 2, 7]
+    );
+    grid.rotate_column(2.try_into()?, -4);
+    assert_eq!(grid, (0..15).collect::<Grid>());
+    Ok(())
+}
+
+#[test]
+fn test_transpose_in_place() -> Result<()> {
+    let mut grid = (0..25).collect::<Grid5>();
+    let transposed = grid.transpose();
+    grid.transpose_in_place();
+    assert_eq!(grid, transposed);
+    assert_eq!(grid[Pos5::try_from((3, 1))?], 16);
+    grid.transpose_in_place();
+    assert_eq!(grid, (0..25).collect::<Grid5>());
+    Ok(())
+}
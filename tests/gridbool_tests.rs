@@ -171,6 +171,16 @@ fn test_scale() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_new_boxed() -> Result<()> {
+    assert_eq!(*Gbool1::new_boxed(true), Gbool1::ALL_TRUE);
+    let mut gb = GridboolScale::new_boxed(false);
+    assert_eq!(gb.iter_t().count(), 0);
+    sqrid::SetPos::<PosScale, { 2000 * 2000 / 32 }, 0>::insert(&mut gb, PosScale::LAST);
+    assert_eq!(gb.iter_t().collect::<Vec<_>>(), vec![PosScale::LAST]);
+    Ok(())
+}
+
 #[test]
 fn test_traits() -> Result<()> {
     let g0 = Gbool5::default();
@@ -185,3 +195,111 @@ fn test_traits() -> Result<()> {
     let _ = s.finish();
     Ok(())
 }
+
+#[test]
+fn test_rows_columns() -> Result<()> {
+    // Pos2 is 11x3, with 2 words:
+    let gb = Pos2::iter()
+        .filter(|pos| pos.to_usize() % 4 == 0)
+        .collect::<Gbool2>();
+    let rows = gb
+        .rows()
+        .map(|row| row.map(|b| if b { '#' } else { '.' }).collect::<String>())
+        .collect::<Vec<_>>();
+    assert_eq!(rows, vec!["#...#...#..", ".#...#...#.", "..#...#...#"]);
+    assert_eq!(
+        gb.line(1.try_into()?).collect::<Vec<_>>(),
+        gb.rows().nth(1).unwrap().collect::<Vec<_>>()
+    );
+    assert_eq!(
+        gb.column(2.try_into()?).collect::<Vec<_>>(),
+        vec![false, false, true]
+    );
+    assert_eq!(
+        gb.columns()
+            .map(|column| column.filter(|&b| b).count())
+            .collect::<Vec<_>>(),
+        vec![1, 1, 1, 0, 1, 1, 1, 0, 1, 1, 1]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_set_line_column() -> Result<()> {
+    let mut gb = Gbool2::default();
+    gb.set_line(1.try_into()?, [true, false, true]);
+    assert_eq!(
+        gb.line(1.try_into()?).collect::<Vec<_>>(),
+        [true, false, true]
+            .into_iter()
+            .chain([false; 8])
+            .collect::<Vec<_>>()
+    );
+    // Values past the end are ignored:
+    gb.set_column(10.try_into()?, core::iter::repeat(true));
+    assert_eq!(gb.column(10.try_into()?).collect::<Vec<_>>(), vec![true; 3]);
+    gb.set_column(0.try_into()?, [false, false]);
+    assert_eq!(gb.iter_t().count(), 4);
+    gb.set_line(0.try_into()?, [false; 11]);
+    gb.set_line(2.try_into()?, [false; 11]);
+    assert_eq!(
+        gb.iter_t().collect::<Vec<_>>(),
+        vec![Pos2::try_from((2, 1))?, Pos2::try_from((10, 1))?]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_swap_rotate_lines() -> Result<()> {
+    let gb = Pos2::iter()
+        .filter(|pos| pos.to_usize() % 4 == 0)
+        .collect::<Gbool2>();
+    let mut gb2 = gb;
+    gb2.swap_rows(0.try_into()?, 2.try_into()?);
+    assert_eq!(
+        gb2.line(0.try_into()?).collect::<Vec<_>>(),
+        gb.line(2.try_into()?).collect::<Vec<_>>()
+    );
+    assert_eq!(
+        gb2.line(2.try_into()?).collect::<Vec<_>>(),
+        gb.line(0.try_into()?).collect::<Vec<_>>()
+    );
+    gb2.swap_rows(0.try_into()?, 2.try_into()?);
+    gb2.swap_columns(0.try_into()?, 3.try_into()?);
+    assert_eq!(
+        gb2.column(3.try_into()?).collect::<Vec<_>>(),
+        vec![true, false, false]
+    );
+    assert!(gb2.column(0.try_into()?).all(|b| !b));
+    let mut gb2 = gb;
+    gb2.rotate_row(0.try_into()?, 3);
+    assert_eq!(
+        gb2.line(0.try_into()?)
+            .map(|b| if b { '#' } else { '.' })
+            .collect::<String>(),
+        "#..#...#..."
+    );
+    gb2.rotate_row(0.try_into()?, -14);
+    assert_eq!(gb2, gb);
+    gb2.rotate_column(1.try_into()?, -1);
+    assert_eq!(
+        gb2.column(1.try_into()?).collect::<Vec<_>>(),
+        vec![true, false, false]
+    );
+    gb2.rotate_column(1.try_into()?, 4);
+    assert_eq!(gb2, gb);
+    Ok(())
+}
+
+#[test]
+fn test_transpose_in_place() -> Result<()> {
+    let mut gb = Pos5::iter()
+        .filter(|pos| pos.tuple().0 == 0 || pos.to_usize() == 7)
+        .collect::<Gbool5>();
+    let transposed = gb.transpose();
+    gb.transpose_in_place();
+    assert_eq!(gb, transposed);
+    assert!(gb.line(0.try_into()?).all(|b| b));
+    assert!(gb.get(&Pos5::try_from((1, 2))?));
+    Ok(())
+}
@@ -0,0 +1,124 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use sqrid::bf;
+use sqrid::ucs;
+use sqrid::Dir;
+use sqrid::Error;
+use sqrid::InfiniteGrid;
+use sqrid::MapPos;
+use sqrid::SetPos;
+
+type P = (i32, i32);
+
+#[test]
+fn test_get_set() {
+    let mut grid = InfiniteGrid::<u8>::default();
+    assert_eq!(grid.bounding_box(), None);
+    assert_eq!(grid.iter_pos().count(), 0);
+    assert_eq!(grid[(i32::MIN, i32::MAX)], 0);
+    grid[(-1, -1)] = 1;
+    grid.set((0, 0), 2);
+    grid[&(15, 15)] = 3;
+    assert_eq!(grid.chunks_len(), 2);
+    assert_eq!(grid[(-1, -1)], 1);
+    assert_eq!(*grid.get(&(0, 0)), 2);
+    assert_eq!(grid[&(15, 15)], 3);
+    assert_eq!(grid[(16, 15)], 0);
+    assert_eq!(grid.bounding_box(), Some(((-1, -1), (15, 15))));
+    assert_eq!(grid.iter_pos().count(), 17 * 17);
+    assert_eq!(grid.iter_pos().map(|(_, &v)| v as usize).sum::<usize>(), 6);
+    assert_eq!(grid.iter_pos().next(), Some(((-1, -1), &1)));
+    // Reading doesn't allocate:
+    assert_eq!(grid[(100, 100)], 0);
+    assert_eq!(grid.chunks_len(), 2);
+    assert_eq!(grid.default_value(), &0);
+}
+
+#[test]
+fn test_mappos_setpos() {
+    let mut grid = <InfiniteGrid<usize> as MapPos<usize, P, 0, 0>>::new(7);
+    MapPos::<usize, P, 0, 0>::set(&mut grid, (-20, 30), 3);
+    assert_eq!(*MapPos::<usize, P, 0, 0>::get(&grid, &(-20, 30)), 3);
+    assert_eq!(*MapPos::<usize, P, 0, 0>::get(&grid, &(20, 30)), 7);
+    let mut set = InfiniteGrid::<bool>::default();
+    SetPos::<P, 0, 0>::insert(&mut set, (-5, -5));
+    assert!(SetPos::<P, 0, 0>::contains(&set, &(-5, -5)));
+    SetPos::<P, 0, 0>::remove(&mut set, &(-5, -5));
+    assert!(!SetPos::<P, 0, 0>::contains(&set, &(-5, -5)));
+}
+
+#[test]
+fn test_default_writes() {
+    let mut set = InfiniteGrid::<bool>::default();
+    // Removing and setting the default value are not changes:
+    SetPos::<P, 0, 0>::remove(&mut set, &(100, 100));
+    set.set((-100, -100), false);
+    assert_eq!(set.bounding_box(), None);
+    assert_eq!(set.chunks_len(), 0);
+    SetPos::<P, 0, 0>::insert(&mut set, (1, 2));
+    SetPos::<P, 0, 0>::remove(&mut set, &(50, 50));
+    assert_eq!(set.bounding_box(), Some(((1, 2), (1, 2))));
+    assert_eq!(set.chunks_len(), 1);
+    // Setting the default value in an existing chunk:
+    SetPos::<P, 0, 0>::remove(&mut set, &(1, 2));
+    assert!(!SetPos::<P, 0, 0>::contains(&set, &(1, 2)));
+    assert_eq!(set.chunks_len(), 1);
+    // Mutable access always counts as a change:
+    set[(40, 40)] = false;
+    assert_eq!(set.bounding_box(), Some(((1, 2), (40, 40))));
+    assert_eq!(set.chunks_len(), 2);
+}
+
+/// Walls around the origin, with a bounded search region
+fn go(pos: P, dir: Dir) -> Option<P> {
+    let next = (pos + dir).ok()?;
+    if next.0.abs() > 40 || next.1.abs() > 40 {
+        return None;
+    }
+    // Vertical wall at x = 1, open only at y = -30
+    if next.0 == 1 && next.1 != -30 {
+        return None;
+    }
+    Some(next)
+}
+
+#[test]
+fn test_bf() -> Result<(), Error> {
+    let orig = (-3, 5);
+    let visited = bf::bf_iter::<_, InfiniteGrid<bool>, P, false, 0, 0>(go, &orig)
+        .flatten()
+        .count();
+    // The whole region minus the wall, minus the origin:
+    assert_eq!(visited, 81 * 81 - 80 - 1);
+    let dest = (3, 5);
+    let path =
+        bf::search_path::<_, _, InfiniteGrid<Option<Dir>>, InfiniteGrid<bool>, P, false, 0, 0>(
+            go,
+            &orig,
+            |pos| pos == dest,
+        )?;
+    assert_eq!(path.1.len(), 4 + 35 + 35 + 2);
+    Ok(())
+}
+
+#[test]
+fn test_ucs() -> Result<(), Error> {
+    let orig = (-3, 5);
+    let dest = (3, 5);
+    let path = ucs::search_path::<_, InfiniteGrid<Option<Dir>>, InfiniteGrid<usize>, P, false, 0, 0>(
+        |pos, dir| go(pos, dir).map(|next| (next, 1)),
+        &orig,
+        &dest,
+    )?;
+    assert_eq!(path.len(), 4 + 35 + 35 + 2);
+    let unreachable =
+        ucs::search_path::<_, InfiniteGrid<Option<Dir>>, InfiniteGrid<usize>, P, false, 0, 0>(
+            |pos, dir| go(pos, dir).map(|next| (next, 1)),
+            &orig,
+            &(100, 100),
+        );
+    assert_eq!(unreachable, Err(Error::DestinationUnreachable));
+    Ok(())
+}
@@ -0,0 +1,149 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use sqrid::maze::Algorithm;
+use sqrid::maze::Openings;
+use sqrid::postrait::PosT;
+use sqrid::Dir;
+
+use anyhow::Result;
+
+type Sqrid = sqrid::sqrid_create!(11, 7, false);
+type Pos = sqrid::pos_create!(Sqrid);
+type Maze = sqrid::grid_create!(Sqrid, Openings);
+
+type SqridWalls = sqrid::sqrid_create!(24, 16, false);
+type PosWalls = sqrid::pos_create!(SqridWalls);
+type Walls = sqrid::gridbool_create!(SqridWalls);
+
+const ALGORITHMS: [Algorithm; 5] = [
+    Algorithm::Backtracker,
+    Algorithm::Prim,
+    Algorithm::Kruskal,
+    Algorithm::Wilson,
+    Algorithm::Eller,
+];
+
+fn lcg(mut seed: u64) -> impl FnMut(usize) -> usize {
+    move |n| {
+        seed = seed
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (seed >> 33) as usize % n
+    }
+}
+
+fn check_consistent(maze: &Maze) {
+    for pos in Pos::iter() {
+        for dir in Dir::iter::<true>() {
+            if !maze[pos].contains(dir) {
+                continue;
+            }
+            assert!(!dir.is_diagonal());
+            let next = (pos + dir).expect("passage out of the maze");
+            assert!(maze[next].contains(-dir));
+        }
+    }
+}
+
+fn passages(maze: &Maze) -> usize {
+    maze.iter().map(Openings::len).sum::<usize>() / 2
+}
+
+fn reachable(maze: &Maze) -> usize {
+    Sqrid::bf_iter(sqrid::maze::go(maze), &Pos::FIRST)
+        .flatten()
+        .count()
+        + 1
+}
+
+#[test]
+fn test_openings() {
+    let mut openings = Openings::NONE;
+    assert!(openings.is_empty());
+    openings.insert(Dir::N);
+    assert!(openings.is_dead_end());
+    openings.insert(Dir::W);
+    assert_eq!(openings.len(), 2);
+    assert_eq!(openings.iter().collect::<Vec<_>>(), vec![Dir::N, Dir::W]);
+    assert_eq!(format!("{}", openings), "┘");
+    openings.remove(Dir::N);
+    assert!(!openings.contains(Dir::N));
+    assert!(openings.contains(Dir::W));
+}
+
+#[test]
+fn test_perfect() -> Result<()> {
+    for algorithm in ALGORITHMS {
+        for seed in 0..5 {
+            let maze: Maze = Sqrid::maze(algorithm, lcg(seed));
+            check_consistent(&maze);
+            // A perfect maze is a spanning tree:
+            assert_eq!(passages(&maze), Pos::SIZE - 1, "{:?}", algorithm);
+            assert_eq!(reachable(&maze), Pos::SIZE, "{:?}", algorithm);
+            let path = Sqrid::bfs_path(sqrid::maze::go(&maze), &Pos::TOP_LEFT, |pos| {
+                pos == Pos::BOTTOM_RIGHT
+            })?;
+            assert!(path.1.len() >= Pos::TOP_LEFT.manhattan(&Pos::BOTTOM_RIGHT));
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_deterministic() {
+    for algorithm in ALGORITHMS {
+        let maze1: Maze = Sqrid::maze(algorithm, lcg(7));
+        let maze2: Maze = Sqrid::maze(algorithm, lcg(7));
+        assert_eq!(maze1, maze2);
+    }
+}
+
+#[test]
+fn test_braid() {
+    for algorithm in ALGORITHMS {
+        let mut maze: Maze = Sqrid::maze(algorithm, lcg(3));
+        sqrid::maze::braid(&mut maze, lcg(4));
+        check_consistent(&maze);
+        assert!(maze.iter().all(|openings| !openings.is_dead_end()));
+        assert!(passages(&maze) >= Pos::SIZE);
+        assert_eq!(reachable(&maze), Pos::SIZE);
+    }
+}
+
+#[test]
+fn test_walls() -> Result<()> {
+    let maze: Maze = Sqrid::maze(Algorithm::Kruskal, lcg(1));
+    let walls: Walls = sqrid::maze::walls(&maze)?;
+    // Borders and even-even corners are walls:
+    for pos in PosWalls::iter() {
+        let (x, y) = pos.tuple();
+        if x == 0 || y == 0 || x == 24 || y == 16 || (x % 2 == 0 && y % 2 == 0) {
+            assert!(walls[pos], "{:?}", pos);
+        }
+        if x % 2 == 1 && y % 2 == 1 {
+            assert!(!walls[pos], "{:?}", pos);
+        }
+    }
+    // Same number of open positions as cells and passages:
+    assert_eq!(walls.iter_f().count(), Pos::SIZE + passages(&maze));
+    // The same path can be found in both:
+    let path = Sqrid::bfs_path(sqrid::maze::go(&maze), &Pos::TOP_LEFT, |pos| {
+        pos == Pos::BOTTOM_RIGHT
+    })?;
+    let wallpath = SqridWalls::bfs_path(
+        |pos: PosWalls, dir| (pos + dir).ok().filter(|next| !walls[next]),
+        &PosWalls::new(1, 1)?,
+        |pos| pos == PosWalls::new(23, 15).unwrap(),
+    )?;
+    assert_eq!(wallpath.1.len(), 2 * path.1.len());
+    // A walls grid that is too small:
+    type Small = sqrid::gridbool_create!(Sqrid);
+    assert_eq!(
+        sqrid::maze::walls::<_, Pos, { Pos::SIZE }, { Pos::SIZE.div_ceil(32) }>(&maze)
+            .map(|_: Small| ()),
+        Err(sqrid::Error::OutOfBounds)
+    );
+    Ok(())
+}
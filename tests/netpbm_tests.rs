@@ -0,0 +1,191 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use sqrid::postrait::PosT;
+use sqrid::Error;
+
+use anyhow::Result;
+
+type Sqrid = sqrid::sqrid_create!(10, 2, false);
+type Pos = sqrid::pos_create!(Sqrid);
+type Grid = sqrid::grid_create!(Sqrid, usize);
+type Gridbool = sqrid::gridbool_create!(Sqrid);
+
+fn pattern() -> Gridbool {
+    Pos::iter().map(|pos| pos.to_usize() % 3 == 0).collect()
+}
+
+#[test]
+fn test_pbm() -> Result<()> {
+    let gb = pattern();
+    let pbm = gb.to_pbm();
+    // 11 columns take 2 bytes per row:
+    assert!(pbm.starts_with(b"P4\n11 3\n"));
+    assert_eq!(pbm.len(), b"P4\n11 3\n".len() + 2 * 3);
+    assert_eq!(pbm[8..10], [0b10010010, 0b01000000]);
+    assert_eq!(Gridbool::from_pbm(&pbm)?, gb);
+    for scale in [2, 3, 8] {
+        let pbm = gb.to_pbm_scaled(scale);
+        assert_eq!(Gridbool::from_pbm_scaled(&pbm, scale)?, gb);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_pbm_plain() -> Result<()> {
+    let plain = b"P1\n# comment\n11 3\n10010010010\n0 1 0 0 1 0 0 1 0 0 1\n00100100100\n";
+    assert_eq!(Gridbool::from_pbm(plain)?, pattern());
+    Ok(())
+}
+
+#[test]
+fn test_pgm() -> Result<()> {
+    let grid = (0..Pos::SIZE).collect::<Grid>();
+    let pgm = grid.to_pgm(|&v| (v * 7) as u8);
+    assert!(pgm.starts_with(b"P5\n11 3\n255\n"));
+    assert_eq!(Grid::from_pgm(&pgm, |v| usize::from(v) / 7)?, grid);
+    let pgm = grid.to_pgm_scaled(4, |&v| (v * 7) as u8);
+    assert!(pgm.starts_with(b"P5\n44 12\n255\n"));
+    assert_eq!(
+        Grid::from_pgm_scaled(&pgm, 4, |v| usize::from(v) / 7)?,
+        grid
+    );
+    Ok(())
+}
+
+#[test]
+fn test_pgm_maxval() -> Result<()> {
+    type Sqrid = sqrid::sqrid_create!(1, 0, false);
+    type Grid = sqrid::grid_create!(Sqrid, u8);
+    // Plain, with a small maxval:
+    let grid = Grid::from_pgm(b"P2 2 1 3 0 3", |v| v)?;
+    assert_eq!(grid.as_array(), &[0, 255]);
+    // Binary, 16 bits per sample:
+    let grid = Grid::from_pgm(b"P5 2 1 65535\n\x00\x00\xff\xff", |v| v)?;
+    assert_eq!(grid.as_array(), &[0, 255]);
+    Ok(())
+}
+
+#[test]
+fn test_ppm() -> Result<()> {
+    let grid = (0..Pos::SIZE).collect::<Grid>();
+    let color = |&v: &usize| (v as u8, (v * 2) as u8, 255 - v as u8);
+    let ppm = grid.to_ppm(color);
+    assert!(ppm.starts_with(b"P6\n11 3\n255\n"));
+    assert_eq!(ppm.len(), b"P6\n11 3\n255\n".len() + 3 * Pos::SIZE);
+    assert_eq!(Grid::from_ppm(&ppm, |(r, _, _)| usize::from(r))?, grid);
+    let ppm = grid.to_ppm_scaled(2, color);
+    assert_eq!(
+        Grid::from_ppm_scaled(&ppm, 2, |(_, g, _)| usize::from(g) / 2)?,
+        grid
+    );
+    let plain = b"P3 1 1 255 1 2 3";
+    type Sqrid1 = sqrid::sqrid_create!(0, 0, false);
+    type Grid1 = sqrid::grid_create!(Sqrid1, (u8, u8, u8));
+    assert_eq!(Grid1::from_ppm(plain, |c| c)?.as_array(), &[(1, 2, 3)]);
+    Ok(())
+}
+
+#[test]
+fn test_errors() {
+    let gb = pattern();
+    // Wrong dimensions and scale:
+    assert_eq!(
+        Gridbool::from_pbm(&gb.to_pbm_scaled(2)),
+        Err(Error::OutOfBounds)
+    );
+    assert_eq!(
+        Gridbool::from_pbm_scaled(&gb.to_pbm(), 0),
+        Err(Error::OutOfBounds)
+    );
+    // Wrong format:
+    let grid = Grid::default();
+    assert_eq!(
+        Gridbool::from_pbm(&grid.to_pgm(|_| 0)),
+        Err(Error::ImageParseError)
+    );
+    assert_eq!(
+        Grid::from_ppm(&grid.to_pgm(|_| 0), |_| 0),
+        Err(Error::ImageParseError)
+    );
+    // Invalid images:
+    for data in [
+        &b""[..],
+        b"P7 11 3",
+        b"P4 11",
+        b"P4 11 3\n\x00",
+        b"P2 11 3 0",
+        b"P2 11 3 5 1 2",
+        b"P1 11 3 2",
+        b"P5 11 3 255",
+    ] {
+        assert_eq!(Gridbool::from_pbm(data), Err(Error::ImageParseError));
+        assert_eq!(
+            Grid::from_pgm(data, usize::from),
+            Err(Error::ImageParseError)
+        );
+    }
+    // Sample above maxval:
+    let mut data = b"P2 11 3 5".to_vec();
+    data.extend(b" 0".repeat(32));
+    data.extend(b" 6");
+    assert_eq!(
+        Grid::from_pgm(&data, usize::from),
+        Err(Error::ImageParseError)
+    );
+}
+
+#[test]
+fn test_hostile_headers() {
+    // Huge dimensions are rejected before reading the raster:
+    assert_eq!(
+        Gridbool::from_pbm(b"P1 3000000000 3000000000"),
+        Err(Error::OutOfBounds)
+    );
+    assert_eq!(
+        Grid::from_pgm(b"P5 4611686018427387904 2 65535\n", usize::from),
+        Err(Error::OutOfBounds)
+    );
+    assert_eq!(
+        Grid::from_ppm(b"P3 18446744073709551615 3 255", |_| 0),
+        Err(Error::OutOfBounds)
+    );
+    // Scales that overflow the expected dimensions:
+    assert_eq!(
+        Gridbool::from_pbm_scaled(b"P4 11 3\n", usize::MAX),
+        Err(Error::OutOfBounds)
+    );
+    // Huge scaled dimensions that match, but without a raster:
+    let scale = 1 << 20;
+    for data in [
+        format!("P1 {} {}", 11 * scale, 3 * scale),
+        format!("P4 {} {}\n", 11 * scale, 3 * scale),
+    ] {
+        assert_eq!(
+            Gridbool::from_pbm_scaled(data.as_bytes(), scale),
+            Err(Error::ImageParseError)
+        );
+    }
+    for data in [
+        format!("P2 {} {} 255", 11 * scale, 3 * scale),
+        format!("P5 {} {} 65535\n", 11 * scale, 3 * scale),
+    ] {
+        assert_eq!(
+            Grid::from_pgm_scaled(data.as_bytes(), scale, usize::from),
+            Err(Error::ImageParseError)
+        );
+    }
+}
+
+#[test]
+#[should_panic(expected = "scaled image dimensions overflow usize")]
+fn test_pbm_scale_overflow() {
+    let _ = pattern().to_pbm_scaled(usize::MAX);
+}
+
+#[test]
+#[should_panic(expected = "scaled image dimensions overflow usize")]
+fn test_pgm_scale_overflow() {
+    let _ = Grid::default().to_pgm_scaled(usize::MAX / 2, |_| 0);
+}
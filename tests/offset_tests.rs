@@ -0,0 +1,98 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use sqrid::postrait::PosT;
+use sqrid::Dir;
+use sqrid::Error;
+use sqrid::Offset;
+
+use anyhow::Result;
+
+type Pos = sqrid::Pos<5, 6>;
+
+#[test]
+fn test_ops() {
+    let a = Offset::new(2, -3);
+    let b = Offset::from((-1, 4));
+    assert_eq!(a + b, Offset::new(1, 1));
+    assert_eq!(a - b, Offset::new(3, -7));
+    assert_eq!(-a, Offset::new(-2, 3));
+    assert_eq!(a * 3, Offset::new(6, -9));
+    assert_eq!(a + -a, Offset::ZERO);
+    let mut c = a;
+    c += b;
+    c -= a;
+    assert_eq!(c, b);
+    assert_eq!(a.manhattan(), 5);
+    assert_eq!(a.chebyshev(), 3);
+    assert_eq!(<(i32, i32)>::from(a), (2, -3));
+    assert_eq!(a.to_string(), "(2,-3)");
+}
+
+#[test]
+fn test_dir() {
+    for dir in Dir::iter::<true>() {
+        assert_eq!(Offset::from(dir), dir * 1);
+        assert_eq!(Dir::try_from(Offset::from(dir)), Ok(dir));
+        assert_eq!(dir * -2, (-dir) * 2);
+    }
+    assert_eq!(Dir::NE * 4, Offset::new(4, -4));
+    assert_eq!(Dir::try_from(Dir::S * 2), Err(Error::InvalidDirection));
+    assert_eq!(Dir::try_from(Offset::ZERO), Err(Error::InvalidDirection));
+}
+
+#[test]
+fn test_pos() -> Result<()> {
+    for pos1 in Pos::iter() {
+        for pos2 in Pos::iter() {
+            let offset = pos2 - pos1;
+            assert_eq!(pos1 + offset, Ok(pos2));
+            assert_eq!(pos2 - offset, Ok(pos1));
+            assert_eq!(offset.manhattan() as usize, pos1.manhattan(&pos2));
+        }
+        for dir in Dir::iter::<true>() {
+            assert_eq!(pos1 + dir * 1, pos1 + dir);
+        }
+    }
+    let pos = Pos::new(1, 2)?;
+    assert_eq!(pos + Dir::E * 4, Ok(Pos::new(5, 2)?));
+    assert_eq!(pos + Dir::E * 5, Err(Error::OutOfBounds));
+    assert_eq!(pos + Dir::W * 2, Err(Error::OutOfBounds));
+    assert_eq!(pos + Offset::new(i32::MAX, 0), Err(Error::OutOfBounds));
+    assert_eq!(pos + Offset::new(0, i32::MIN), Err(Error::OutOfBounds));
+    assert_eq!(pos + Offset::new(-65535, 0), Err(Error::OutOfBounds));
+    assert_eq!(pos - Offset::new(i32::MIN, 0), Err(Error::OutOfBounds));
+    assert_eq!(pos - Offset::new(0, i32::MIN), Err(Error::OutOfBounds));
+    assert_eq!(pos - Offset::new(i32::MAX, 0), Err(Error::OutOfBounds));
+    assert_eq!(pos - Offset::new(-4, 1), Ok(Pos::new(5, 1)?));
+    Ok(())
+}
+
+#[test]
+fn test_checked() {
+    let offset = Offset::new(3, -2);
+    assert_eq!(
+        offset.checked_add(Offset::new(1, 1)),
+        Some(Offset::new(4, -1))
+    );
+    assert_eq!(offset.checked_add(Offset::new(i32::MAX, 0)), None);
+    assert_eq!(
+        offset.checked_sub(Offset::new(1, 1)),
+        Some(Offset::new(2, -3))
+    );
+    assert_eq!(offset.checked_sub(Offset::new(0, i32::MAX)), None);
+    assert_eq!(offset.checked_neg(), Some(-offset));
+    assert_eq!(Offset::new(0, i32::MIN).checked_neg(), None);
+    assert_eq!(offset.checked_mul(2), Some(offset * 2));
+    assert_eq!(offset.checked_mul(i32::MAX), None);
+}
+
+#[test]
+fn test_tryfrom_negative() -> Result<()> {
+    assert_eq!(Pos::try_from((3_i32, 4_i32))?, Pos::new(3, 4)?);
+    assert_eq!(Pos::try_from((-1_i32, 0_i32)), Err(Error::OutOfBounds));
+    assert_eq!(Pos::try_from((0_i32, -65535_i32)), Err(Error::OutOfBounds));
+    assert_eq!(Pos::try_from(&(65536_i32, 0_i32)), Err(Error::OutOfBounds));
+    Ok(())
+}
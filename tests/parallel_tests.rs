@@ -0,0 +1,161 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![cfg(feature = "rayon")]
+
+use rayon::prelude::*;
+
+use sqrid::postrait::PosT;
+use sqrid::ucs::UcsIterator;
+use sqrid::Dir;
+
+type Sqrid = sqrid::sqrid_create!(13, 97, false);
+type Pos = sqrid::pos_create!(Sqrid);
+type Grid = sqrid::grid_create!(Sqrid, usize);
+type Gridbool = sqrid::gridbool_create!(Sqrid);
+
+type SqridD = sqrid::sqrid_create!(13, 97, true);
+
+const WORDS: usize = Pos::SIZE / 32 + 1;
+
+fn lcg(mut seed: u64) -> impl FnMut(usize) -> usize {
+    move |n| {
+        seed = seed
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (seed >> 33) as usize % n
+    }
+}
+
+fn random_gridbool(seed: u64, percent: usize) -> Gridbool {
+    let mut rng = lcg(seed);
+    Pos::iter().map(|_| rng(100) < percent).collect()
+}
+
+/// Sequential reference: label the components with bf_iter
+fn components_seq<const D: bool>(gb: &Gridbool) -> (Grid, usize) {
+    let mut labels = Grid::default();
+    let mut count = 0;
+    for pos in Pos::iter() {
+        if !gb.get(&pos) || labels[pos] != 0 {
+            continue;
+        }
+        count += 1;
+        labels[pos] = count;
+        let go = |p: Pos, dir: Dir| (p + dir).ok().filter(|p| gb.get(p));
+        for (p, _) in sqrid::bf::bf_iter_grid::<_, _, D, WORDS, { Pos::SIZE }>(go, &pos).flatten() {
+            labels[p] = count;
+        }
+    }
+    (labels, count)
+}
+
+#[test]
+fn test_par_iter_pos() {
+    let grid = (0..Pos::SIZE).collect::<Grid>();
+    let collected = grid
+        .par_iter_pos()
+        .map(|(pos, &value)| (pos, value))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        collected,
+        grid.iter_pos()
+            .map(|(pos, &v)| (pos, v))
+            .collect::<Vec<_>>()
+    );
+    assert!(grid
+        .par_iter_pos()
+        .all(|(pos, &value)| pos.to_usize() == value));
+}
+
+#[test]
+fn test_par_map() {
+    let grid = (0..Pos::SIZE).collect::<Grid>();
+    let mapped = grid.par_map(|&v| v % 7 == 0);
+    for (pos, &value) in grid.iter_pos() {
+        assert_eq!(mapped[pos], value % 7 == 0);
+    }
+}
+
+#[test]
+fn test_components() {
+    // The number of threads defines the number of strips that are
+    // merged:
+    for threads in [1, 2, 7, 32, 200] {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build()
+            .unwrap();
+        for seed in 0..5 {
+            for percent in [0, 30, 55, 70, 100] {
+                let gb = random_gridbool(seed, percent);
+                let (labels, count) = pool.install(|| Sqrid::par_components(&gb));
+                assert_eq!((*labels, count), components_seq::<false>(&gb));
+                let (labels, count) = pool.install(|| SqridD::par_components(&gb));
+                assert_eq!((*labels, count), components_seq::<true>(&gb));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_bf_distances() {
+    let walls = random_gridbool(7, 30);
+    let go = |pos: Pos, dir: Dir| (pos + dir).ok().filter(|p| !walls.get(p));
+    let mut rng = lcg(3);
+    let origins = (0..8)
+        .map(|_| Pos::tryfrom_usize(rng(Pos::SIZE)).unwrap())
+        .collect::<Vec<_>>();
+    let mut dests = (0..20)
+        .map(|_| Pos::tryfrom_usize(rng(Pos::SIZE)).unwrap())
+        .collect::<Vec<_>>();
+    // Repeated destinations and origins as destinations:
+    dests.push(dests[0]);
+    dests.push(origins[0]);
+    let distances = Sqrid::par_bf_distances(go, &origins, &dests);
+    assert_eq!(distances.len(), origins.len());
+    for (orig, row) in origins.iter().zip(distances) {
+        let mut expected = Grid::repeat(usize::MAX);
+        expected[orig] = 0;
+        for (distance, front) in Sqrid::bf_iter(go, orig).enumerate() {
+            for (pos, _) in front {
+                expected[pos] = distance + 1;
+            }
+        }
+        for (dest, distance) in dests.iter().zip(row) {
+            assert_eq!(distance, Some(expected[dest]).filter(|&d| d != usize::MAX));
+        }
+    }
+    assert!(Sqrid::par_bf_distances(go, &origins, &[])
+        .iter()
+        .all(Vec::is_empty));
+}
+
+#[test]
+fn test_ucs_distances() {
+    let walls = random_gridbool(11, 30);
+    let go = |pos: Pos, dir: Dir| {
+        let next = (pos + dir).ok().filter(|p| !walls.get(p))?;
+        Some((next, 1 + next.to_usize() % 5))
+    };
+    let mut rng = lcg(5);
+    let origins = (0..8)
+        .map(|_| Pos::tryfrom_usize(rng(Pos::SIZE)).unwrap())
+        .collect::<Vec<_>>();
+    let mut dests = (0..20)
+        .map(|_| Pos::tryfrom_usize(rng(Pos::SIZE)).unwrap())
+        .collect::<Vec<_>>();
+    dests.push(origins[1]);
+    let costs = Sqrid::par_ucs_distances(go, &origins, &dests);
+    for (orig, row) in origins.iter().zip(costs) {
+        let mut expected = Grid::repeat(usize::MAX);
+        let it = UcsIterator::<_, Grid, Pos, false, WORDS, { Pos::SIZE }>::new(go, orig);
+        for (pos, _, cost) in it {
+            expected[pos] = cost;
+        }
+        for (dest, cost) in dests.iter().zip(row) {
+            assert_eq!(cost, Some(expected[dest]).filter(|&c| c != usize::MAX));
+        }
+    }
+}
@@ -0,0 +1,315 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use sqrid::polygon;
+use sqrid::polygon::Location;
+use sqrid::postrait::PosT;
+use sqrid::Dir;
+use sqrid::Error;
+
+use anyhow::Result;
+
+type Sqrid = sqrid::sqrid_create!(9, 11, false);
+type Pos = sqrid::pos_create!(Sqrid);
+
+fn lagoon() -> Vec<(Dir, i64)> {
+    [
+        ('E', 6),
+        ('S', 5),
+        ('W', 2),
+        ('S', 2),
+        ('E', 2),
+        ('S', 2),
+        ('W', 5),
+        ('N', 2),
+        ('W', 1),
+        ('N', 2),
+        ('E', 2),
+        ('N', 3),
+        ('W', 2),
+        ('N', 2),
+    ]
+    .into_iter()
+    .map(|(c, len)| (Dir::try_from(c).unwrap(), len))
+    .collect()
+}
+
+fn check_interior(vertices: &[(i64, i64)]) {
+    let gridbool = polygon::interior::<Pos, { Pos::SIZE.div_ceil(32) }>(vertices);
+    for pos in Pos::iter() {
+        let (x, y) = pos.tuple();
+        let location = polygon::locate(vertices, (x as i64, y as i64));
+        assert_eq!(gridbool[pos], location == Location::Inside, "{:?}", pos);
+    }
+}
+
+#[test]
+fn test_area() -> Result<()> {
+    let area = polygon::area(lagoon())?;
+    assert_eq!(area.boundary, 38);
+    assert_eq!(area.double_area, 84);
+    assert_eq!(area.total(), 62);
+    let path = [
+        Dir::E,
+        Dir::E,
+        Dir::S,
+        Dir::S,
+        Dir::W,
+        Dir::W,
+        Dir::N,
+        Dir::N,
+    ];
+    let area = polygon::area_path(&path)?;
+    assert_eq!((area.boundary, area.interior), (8, 1));
+    // Diagonals:
+    let diamond = [
+        Dir::NE,
+        Dir::NE,
+        Dir::SE,
+        Dir::SE,
+        Dir::SW,
+        Dir::SW,
+        Dir::NW,
+        Dir::NW,
+    ];
+    let area = polygon::area_path(&diamond)?;
+    assert_eq!((area.double_area, area.boundary, area.interior), (16, 8, 5));
+    // Huge loops:
+    let size = 1_000_000_000;
+    let steps = [
+        (Dir::W, size),
+        (Dir::N, size),
+        (Dir::E, size),
+        (Dir::S, size),
+    ];
+    assert_eq!(polygon::area(steps)?.total(), (size + 1) * (size + 1));
+    let size = 4_000_000_000;
+    let steps = [
+        (Dir::W, size),
+        (Dir::N, size),
+        (Dir::E, size),
+        (Dir::S, size),
+    ];
+    assert_eq!(polygon::area(steps), Err(Error::OutOfBounds));
+    // Open paths:
+    assert_eq!(polygon::area_path(&[Dir::N, Dir::E]), Err(Error::NotClosed));
+    Ok(())
+}
+
+#[test]
+fn test_locate() {
+    let vertices = polygon::vertices((0, 0), lagoon()).unwrap();
+    assert_eq!(vertices.first(), vertices.last());
+    let grid = [
+        "#######", "#.....#", "###...#", "..#...#", "..#...#", "###.###", "#...#..", "##..###",
+        ".#....#", ".######",
+    ];
+    let mut interior = 0;
+    for (y, line) in grid.iter().enumerate() {
+        for (x, c) in line.chars().enumerate() {
+            let location = polygon::locate(&vertices, (x as i64, y as i64));
+            let expected = match c {
+                '#' => Location::Boundary,
+                _ => location,
+            };
+            assert_eq!(location, expected, "{} {}", x, y);
+            interior += (location == Location::Inside) as i64;
+        }
+        assert_eq!(
+            polygon::locate(&vertices, (-1, y as i64)),
+            Location::Outside
+        );
+    }
+    assert_eq!(interior, polygon::area(lagoon()).unwrap().interior);
+    assert_eq!(polygon::locate(&vertices, (0, 3)), Location::Outside);
+    assert_eq!(polygon::locate(&vertices, (1, 6)), Location::Inside);
+}
+
+#[test]
+fn test_extreme_coordinates() -> Result<()> {
+    // Vertices that don't fit in an i64:
+    let steps = [(Dir::E, i64::MAX), (Dir::E, 1)];
+    assert_eq!(polygon::vertices((0, 0), steps), Err(Error::OutOfBounds));
+    assert_eq!(polygon::area(steps), Err(Error::OutOfBounds));
+    // Coordinate differences that don't fit in an i64:
+    let half = i64::MAX / 2 + 1;
+    let steps = [
+        (Dir::E, i64::MAX),
+        (Dir::S, i64::MAX),
+        (Dir::W, i64::MAX),
+        (Dir::N, i64::MAX),
+    ];
+    let vertices = polygon::vertices((-half, -half), steps)?;
+    assert_eq!(polygon::locate(&vertices, (0, 0)), Location::Inside);
+    assert_eq!(polygon::locate(&vertices, (-half, 0)), Location::Boundary);
+    assert_eq!(
+        polygon::locate(&vertices, (i64::MAX, i64::MAX)),
+        Location::Outside
+    );
+    let gridbool = polygon::interior::<Pos, { Pos::SIZE.div_ceil(32) }>(&vertices);
+    assert_eq!(gridbool.iter_f().count(), 0);
+    Ok(())
+}
+
+#[test]
+fn test_interior() -> Result<()> {
+    let vertices = polygon::vertices((1, 1), lagoon())?;
+    check_interior(&vertices);
+    let gridbool = polygon::interior::<Pos, { Pos::SIZE.div_ceil(32) }>(&vertices);
+    assert_eq!(
+        gridbool.iter_t().count() as i64,
+        polygon::area(lagoon())?.interior
+    );
+    // Partially outside of the grid:
+    for start in [(-3, -2), (5, 4), (-2, 6), (4, -5)] {
+        check_interior(&polygon::vertices(start, lagoon())?);
+    }
+    // Diagonals:
+    let diamond = [(Dir::NE, 5), (Dir::SE, 5), (Dir::SW, 5), (Dir::NW, 5)];
+    let vertices = polygon::vertices((0, 5), diamond)?;
+    check_interior(&vertices);
+    let gridbool = polygon::interior::<Pos, { Pos::SIZE.div_ceil(32) }>(&vertices);
+    assert_eq!(
+        gridbool.iter_t().count() as i64,
+        polygon::area(diamond)?.interior
+    );
+    // Loops that are much larger than the grid:
+    let size = 1 << 40;
+    let steps = [
+        (Dir::E, size),
+        (Dir::S, size),
+        (Dir::W, size),
+        (Dir::N, size),
+    ];
+    let gridbool = polygon::interior::<Pos, { Pos::SIZE.div_ceil(32) }>(&polygon::vertices(
+        (-size / 2, -size / 2),
+        steps,
+    )?);
+    assert_eq!(gridbool.iter_f().count(), 0);
+    Ok(())
+}
+
+fn pipes(c: char) -> Vec<Dir> {
+    match c {
+        '|' => vec![Dir::N, Dir::S],
+        '-' => vec![Dir::E, Dir::W],
+        'L' => vec![Dir::N, Dir::E],
+        'J' => vec![Dir::N, Dir::W],
+        '7' => vec![Dir::S, Dir::W],
+        'F' => vec![Dir::S, Dir::E],
+        _ => vec![],
+    }
+}
+
+/// Walk the loop of pipes that starts at `S`
+fn pipe_loop(lines: &[&str]) -> Vec<(i64, i64)> {
+    let chars = lines
+        .iter()
+        .map(|l| l.chars().collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    let at = |(x, y): (i64, i64)| {
+        chars
+            .get(y as usize)
+            .and_then(|l| l.get(x as usize))
+            .copied()
+            .unwrap_or('.')
+    };
+    let step = |(x, y): (i64, i64), dir: Dir| {
+        let (dx, dy) = <(i64, i64)>::from(dir);
+        (x + dx, y + dy)
+    };
+    let y = chars.iter().position(|l| l.contains(&'S')).unwrap();
+    let x = chars[y].iter().position(|&c| c == 'S').unwrap();
+    let start = (x as i64, y as i64);
+    let mut dir = Dir::iter::<false>()
+        .find(|&d| pipes(at(step(start, d))).contains(&-d))
+        .unwrap();
+    let mut path = vec![start];
+    let mut pos = step(start, dir);
+    while pos != start {
+        path.push(pos);
+        dir = pipes(at(pos)).into_iter().find(|&d| d != -dir).unwrap();
+        pos = step(pos, dir);
+    }
+    path
+}
+
+macro_rules! check_fill_enclosed {
+    ($xmax:expr, $ymax:expr, $lines:expr, $inside:expr) => {{
+        type Sqrid = sqrid::sqrid_create!($xmax, $ymax, false);
+        type Pos = sqrid::pos_create!(Sqrid);
+        type Gridbool = sqrid::gridbool_create!(Sqrid);
+        let vertices = pipe_loop($lines);
+        let path = vertices
+            .iter()
+            .map(|&(x, y)| Pos::new(x as u16, y as u16))
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut scanline = Gridbool::default();
+        scanline.fill_enclosed(&path);
+        assert_eq!(scanline.iter_t().count(), $inside);
+        let mut flood = Gridbool::default();
+        Sqrid::fill_enclosed_flood(&mut flood, &path);
+        assert_eq!(flood, scanline);
+        assert_eq!(polygon::interior(&vertices), scanline);
+    }};
+}
+
+#[test]
+fn test_fill_enclosed() -> Result<()> {
+    // Squeezing between pipes:
+    check_fill_enclosed!(
+        9,
+        8,
+        &[
+            "..........",
+            ".S------7.",
+            ".|F----7|.",
+            ".||....||.",
+            ".||....||.",
+            ".|L-7F-J|.",
+            ".|..||..|.",
+            ".L--JL--J.",
+            "..........",
+        ],
+        4
+    );
+    // Touching the borders, with junk pipes inside and outside:
+    check_fill_enclosed!(
+        19,
+        9,
+        &[
+            ".F----7F7F7F7F-7....",
+            ".|F--7||||||||FJ....",
+            ".||.FJ||||||||L7....",
+            "FJL7L7LJLJ||LJ.L-7..",
+            "L--J.L7...LJS7F-7L7.",
+            "....F-J..F7FJ|L7L7L7",
+            "....L7.F7||L7|.L7L7|",
+            ".....|FJLJ|FJ|F7|.LJ",
+            "....FJL-7.||.||||...",
+            "....L---J.LJ.LJLJ...",
+        ],
+        8
+    );
+    check_fill_enclosed!(
+        19,
+        9,
+        &[
+            "FF7FSF7F7F7F7F7F---7",
+            "L|LJ||||||||||||F--J",
+            "FL-7LJLJ||||||LJL-77",
+            "F--JF--7||LJLJ7F7FJ-",
+            "L---JF-JLJ.||-FJLJJ7",
+            "|F|F-JF---7F7-L7L|7|",
+            "|FFJF7L7F-JF7|JL---7",
+            "7-L-JL7||F7|L7F-7F7|",
+            "L.L7LFJ|||||FJL7||LJ",
+            "L7JLJL-JLJLJL--JLJ.L",
+        ],
+        10
+    );
+    // The whole border:
+    check_fill_enclosed!(3, 2, &["S--7", "|..|", "L--J"], 2);
+    Ok(())
+}
@@ -338,6 +338,17 @@ fn test_iter_range() -> Result<()> {
             Pos::try_from((2, 2))?,
         ]
     );
+    // Ranges that end in the last line but not in the last column:
+    let range = Pos::iter_range(Pos::try_from((2, 5))?, Pos::try_from((3, 6))?).collect::<Vec<_>>();
+    assert_eq!(
+        range,
+        vec![
+            Pos::try_from((2, 5))?,
+            Pos::try_from((3, 5))?,
+            Pos::try_from((2, 6))?,
+            Pos::try_from((3, 6))?,
+        ]
+    );
     Ok(())
 }
 
@@ -361,3 +372,87 @@ fn test_tlbr() -> Result<()> {
     assert_eq!((4_u16, 5_u16), br.into());
     Ok(())
 }
+
+#[test]
+fn test_neighbors() -> Result<()> {
+    use sqrid::Dir;
+    let pos = Pos::try_from((0_u16, 3_u16))?;
+    assert_eq!(
+        pos.neighbors::<false>().collect::<Vec<_>>(),
+        vec![
+            (Dir::N, Pos::try_from((0_u16, 2_u16))?),
+            (Dir::E, Pos::try_from((1_u16, 3_u16))?),
+            (Dir::S, Pos::try_from((0_u16, 4_u16))?),
+        ]
+    );
+    assert_eq!(pos.neighbors::<true>().count(), 5);
+    assert_eq!(Pos::TOP_LEFT.neighbors::<true>().count(), 3);
+    assert_eq!(Pos::CENTER.neighbors::<true>().count(), 8);
+    for pos in Pos::iter() {
+        for (dir, neighbor) in pos.neighbors::<true>() {
+            assert_eq!(pos + dir, Ok(neighbor));
+        }
+    }
+    // Wrapping around:
+    assert_eq!(
+        Pos::TOP_LEFT
+            .neighbors_wrapping::<true>()
+            .collect::<Vec<_>>(),
+        vec![
+            (Dir::N, Pos::try_from((0_u16, 6_u16))?),
+            (Dir::NE, Pos::try_from((1_u16, 6_u16))?),
+            (Dir::E, Pos::try_from((1_u16, 0_u16))?),
+            (Dir::SE, Pos::try_from((1_u16, 1_u16))?),
+            (Dir::S, Pos::try_from((0_u16, 1_u16))?),
+            (Dir::SW, Pos::try_from((5_u16, 1_u16))?),
+            (Dir::W, Pos::try_from((5_u16, 0_u16))?),
+            (Dir::NW, Pos::try_from((5_u16, 6_u16))?),
+        ]
+    );
+    assert_eq!(
+        Pos::CENTER.neighbors_wrapping::<true>().collect::<Vec<_>>(),
+        Pos::CENTER.neighbors::<true>().collect::<Vec<_>>()
+    );
+    // Tuples:
+    let tuple = (0_u8, 255_u8);
+    assert_eq!(
+        tuple.neighbors::<false>().collect::<Vec<_>>(),
+        vec![(Dir::N, (0, 254)), (Dir::E, (1, 255))]
+    );
+    assert_eq!(
+        tuple.neighbors_wrapping::<false>().collect::<Vec<_>>(),
+        vec![
+            (Dir::N, (0, 254)),
+            (Dir::E, (1, 255)),
+            (Dir::S, (0, 0)),
+            (Dir::W, (255, 255))
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_count_neighbors_in() -> Result<()> {
+    type Sqrid = sqrid::sqrid_create!(Pos, true);
+    type Gridbool = sqrid::gridbool_create!(Sqrid);
+    let center = Pos::try_from((2_u16, 3_u16))?;
+    let mut gridbool = Gridbool::default();
+    for xy in [(1_u16, 2_u16), (2, 2), (2, 3), (3, 4), (0, 0)] {
+        gridbool.set_t(&Pos::try_from(xy)?);
+    }
+    assert_eq!(Sqrid::count_neighbors_in(&center, &gridbool), 3);
+    assert_eq!(
+        center.count_neighbors_in::<false, { Pos::SIZE.div_ceil(32) }, { Pos::SIZE }>(&gridbool),
+        1
+    );
+    #[cfg(feature = "std")]
+    {
+        let hashset = gridbool.iter_t().collect::<HashSet<_>>();
+        assert_eq!(Sqrid::count_neighbors_in(&center, &hashset), 3);
+        let tuples = [(1_u8, 1_u8), (2, 2), (0, 0)]
+            .into_iter()
+            .collect::<HashSet<_>>();
+        assert_eq!((1_u8, 0_u8).count_neighbors_in::<true, 0, 0>(&tuples), 2);
+    }
+    Ok(())
+}
@@ -0,0 +1,70 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use sqrid::postrait::PosT;
+
+use anyhow::Result;
+
+type Pos = sqrid::Pos<4, 3>;
+type GridI32 = sqrid::grid_create!(Pos, i32);
+type GridU64 = sqrid::grid_create!(Pos, u64);
+type Gridbool = sqrid::gridbool_create!(Pos);
+
+/// Positions of the rectangle with the provided corners, inclusive
+fn rect(topleft: Pos, botright: Pos) -> impl Iterator<Item = Pos> {
+    Pos::iter().filter(move |pos| {
+        (topleft.x()..=botright.x()).contains(&pos.x())
+            && (topleft.y()..=botright.y()).contains(&pos.y())
+    })
+}
+
+fn rects() -> impl Iterator<Item = (Pos, Pos)> {
+    Pos::iter().flat_map(|topleft| {
+        rect(topleft, Pos::BOTTOM_RIGHT).map(move |botright| (topleft, botright))
+    })
+}
+
+#[test]
+fn test_grid_signed() -> Result<()> {
+    let grid = GridI32::from_fn(|pos| pos.to_usize() as i32 * 7 % 11 - 5);
+    let sums = grid.prefix_sums();
+    for (topleft, botright) in rects() {
+        let expected = rect(topleft, botright).map(|pos| grid[pos]).sum::<i32>();
+        assert_eq!(sums.sum(&topleft, &botright), expected);
+    }
+    assert_eq!(sums.total(), grid.iter().sum::<i32>());
+    Ok(())
+}
+
+#[test]
+fn test_grid_unsigned() -> Result<()> {
+    let grid = GridU64::from_fn(|pos| pos.to_usize() as u64 % 3);
+    let sums = grid.prefix_sums();
+    for (topleft, botright) in rects() {
+        let expected = rect(topleft, botright).map(|pos| grid[pos]).sum::<u64>();
+        assert_eq!(sums.sum(&topleft, &botright), expected);
+    }
+    // Single cells, and inverted corners:
+    for pos in Pos::iter() {
+        assert_eq!(sums.sum(&pos, &pos), grid[pos]);
+    }
+    assert_eq!(sums.sum(&Pos::BOTTOM_RIGHT, &Pos::TOP_LEFT), 0);
+    assert_eq!(sums.sum(&Pos::TOP_RIGHT, &Pos::BOTTOM_LEFT), 0);
+    Ok(())
+}
+
+#[test]
+fn test_gridbool() -> Result<()> {
+    let gb = Pos::iter()
+        .filter(|pos| pos.to_usize() % 3 != 1)
+        .collect::<Gridbool>();
+    let sums = gb.prefix_sums();
+    for (topleft, botright) in rects() {
+        let expected = rect(topleft, botright).filter(|pos| gb.get(pos)).count();
+        assert_eq!(sums.sum(&topleft, &botright), expected);
+    }
+    assert_eq!(sums.total(), gb.iter_t().count());
+    assert_eq!(sums.sum(&Pos::new(1, 0)?, &Pos::new(1, 3)?), 2);
+    Ok(())
+}
@@ -14,6 +14,7 @@ type Sqrid = sqrid::sqrid_create!(29, 14, false);
 type Pos = sqrid::pos_create!(Sqrid);
 type GridDir = sqrid::grid_create!(Sqrid, Option<Dir>);
 type Gridbool = sqrid::gridbool_create!(Sqrid);
+type GridUsize = sqrid::grid_create!(Sqrid, usize);
 
 fn walls_from_str(wallstr: &Vec<&str>) -> (Gridbool, Pos, Pos) {
     let mut walls = Gridbool::default();
@@ -81,9 +82,12 @@ fn test_variant(distance: usize, wall: Gridbool, start: &Pos, end: &Pos) -> Resu
         test_path(&wall, &pos, end, &path)?;
         assert_eq!(path.len(), i);
         //   with HashMap:
-        let (_, path) = Sqrid::bfs_path_hash(calc_path(&wall), &pos, goal(&end))?;
-        test_path(&wall, &pos, end, &path)?;
-        assert_eq!(path.len(), i);
+        #[cfg(feature = "std")]
+        {
+            let (_, path) = Sqrid::bfs_path_hash(calc_path(&wall), &pos, goal(&end))?;
+            test_path(&wall, &pos, end, &path)?;
+            assert_eq!(path.len(), i);
+        }
         //   with BTreeMap:
         let (_, path) = Sqrid::bfs_path_btree(calc_path(&wall), &pos, goal(&end))?;
         test_path(&wall, &pos, end, &path)?;
@@ -94,9 +98,12 @@ fn test_variant(distance: usize, wall: Gridbool, start: &Pos, end: &Pos) -> Resu
         test_path(&wall, &pos, end, &path)?;
         assert_eq!(path.len(), i);
         //   with HashMap:
-        let path = Sqrid::astar_path_hash(calc_path(&wall), &pos, end)?;
-        test_path(&wall, &pos, end, &path)?;
-        assert_eq!(path.len(), i);
+        #[cfg(feature = "std")]
+        {
+            let path = Sqrid::astar_path_hash(calc_path(&wall), &pos, end)?;
+            test_path(&wall, &pos, end, &path)?;
+            assert_eq!(path.len(), i);
+        }
         //   with BTreeMap:
         let path = Sqrid::astar_path_btree(calc_path(&wall), &pos, end)?;
         test_path(&wall, &pos, end, &path)?;
@@ -107,9 +114,12 @@ fn test_variant(distance: usize, wall: Gridbool, start: &Pos, end: &Pos) -> Resu
         test_path(&wall, &pos, end, &path)?;
         assert_eq!(path.len(), i);
         //   with HashMap:
-        let path = Sqrid::ucs_path_hash(calc_ucs_path(&wall), &pos, end)?;
-        test_path(&wall, &pos, end, &path)?;
-        assert_eq!(path.len(), i);
+        #[cfg(feature = "std")]
+        {
+            let path = Sqrid::ucs_path_hash(calc_ucs_path(&wall), &pos, end)?;
+            test_path(&wall, &pos, end, &path)?;
+            assert_eq!(path.len(), i);
+        }
         //   with BTreeMap:
         let path = Sqrid::ucs_path_btree(calc_ucs_path(&wall), &pos, end)?;
         test_path(&wall, &pos, end, &path)?;
@@ -180,6 +190,253 @@ fn test_unreachable() -> Result<()> {
     Ok(())
 }
 
+fn path_cost(orig: &Pos, path: &[Dir], cost: impl Fn(Pos, Dir) -> Cost) -> Result<Cost> {
+    let mut pos = *orig;
+    let mut total = 0;
+    for dir in path {
+        total += cost(pos, *dir);
+        pos = (pos + *dir)?;
+    }
+    Ok(total)
+}
+
+#[test]
+fn test_small_costs() -> Result<()> {
+    let orig = Pos::TOP_LEFT;
+    let dest = Pos::BOTTOM_RIGHT;
+    // Digit-like costs, 1..=9:
+    let digit = |pos: Pos, dir: Dir| {
+        let next = (pos + dir).ok()?;
+        Some((
+            next,
+            1 + (next.x() as usize * 7 + next.y() as usize * 3) % 9,
+        ))
+    };
+    let digit_cost = |pos, dir| digit(pos, dir).unwrap().1;
+    let ucs = Sqrid::ucs_path(digit, &orig, &dest)?;
+    let dial = Sqrid::dial_path(digit, &orig, &dest)?;
+    let ucs_cost = path_cost(&orig, &ucs, digit_cost)?;
+    assert_eq!(path_cost(&orig, &dial, digit_cost)?, ucs_cost);
+    #[cfg(feature = "std")]
+    {
+        let dial_hash = Sqrid::dial_path_hash(digit, &orig, &dest)?;
+        assert_eq!(path_cost(&orig, &dial_hash, digit_cost)?, ucs_cost);
+    }
+    // Costs of 0 or 1; moving east is free:
+    let zeroone = |pos: Pos, dir: Dir| {
+        let next = (pos + dir).ok()?;
+        Some((next, if dir == Dir::E { 0 } else { 1 }))
+    };
+    let zeroone_cost = |pos, dir| zeroone(pos, dir).unwrap().1;
+    let ucs = Sqrid::ucs_path(zeroone, &orig, &dest)?;
+    let bfs01 = Sqrid::bfs01_path(zeroone, &orig, &dest)?;
+    let dial = Sqrid::dial_path(zeroone, &orig, &dest)?;
+    let ucs_cost = path_cost(&orig, &ucs, zeroone_cost)?;
+    assert_eq!(ucs_cost, Pos::YMAX as usize);
+    assert_eq!(path_cost(&orig, &bfs01, zeroone_cost)?, ucs_cost);
+    assert_eq!(path_cost(&orig, &dial, zeroone_cost)?, ucs_cost);
+    Ok(())
+}
+
+#[test]
+#[should_panic(expected = "0-1 BFS step cost must be 0 or 1")]
+fn test_bfs01_cost_too_high() {
+    let go = |pos: Pos, dir: Dir| Some(((pos + dir).ok()?, 2));
+    let _ = Sqrid::bfs01_path(go, &Pos::TOP_LEFT, &Pos::BOTTOM_RIGHT);
+}
+
+#[test]
+fn test_bfs01_dial_walls() -> Result<()> {
+    // A wall with a single gap at the bottom:
+    let mut wall = Gridbool::default();
+    for y in 0..Pos::HEIGHT - 1 {
+        wall.set(&Pos::new(14, y)?, true);
+    }
+    let start = Pos::new(2, 2)?;
+    let end = Pos::new(27, 2)?;
+    let go = calc_ucs_path(&wall);
+    let distance = Sqrid::ucs_path(&go, &start, &end)?.len();
+    let paths = [
+        Sqrid::bfs01_path(&go, &start, &end)?,
+        Sqrid::bfs01_path_btree(&go, &start, &end)?,
+        Sqrid::dial_path(&go, &start, &end)?,
+        Sqrid::dial_path_btree(&go, &start, &end)?,
+        #[cfg(feature = "std")]
+        Sqrid::bfs01_path_hash(&go, &start, &end)?,
+        #[cfg(feature = "std")]
+        Sqrid::dial_path_hash(&go, &start, &end)?,
+    ];
+    for path in paths {
+        test_path(&wall, &start, &end, &path)?;
+        assert_eq!(path.len(), distance);
+    }
+    // Closing the gap makes the destination unreachable:
+    let mut closed = wall;
+    closed.set(&Pos::new(14, Pos::HEIGHT - 1)?, true);
+    let go = calc_ucs_path(&closed);
+    assert_eq!(
+        Sqrid::bfs01_path(&go, &start, &end),
+        Err(sqrid::Error::DestinationUnreachable)
+    );
+    assert_eq!(
+        Sqrid::dial_path(&go, &start, &end),
+        Err(sqrid::Error::DestinationUnreachable)
+    );
+    Ok(())
+}
+
+#[test]
+fn test_limits() -> Result<()> {
+    use sqrid::LimitsError::BudgetExceeded;
+    let orig = Pos::TOP_LEFT;
+    let dest = Pos::BOTTOM_RIGHT;
+    let distance = orig.manhattan(&dest);
+    let ucs_go = |pos: Pos, dir: Dir| Some(((pos + dir).ok()?, 1));
+    // Limits that are not reached don't change the result:
+    let limits = sqrid::SearchLimits {
+        max_cost: Some(distance),
+        max_expanded: Some(Pos::SIZE),
+        cancel: Some(&|| false),
+    };
+    let (_, path) = Sqrid::bfs_path_limits(sqrid::pos_dir_add_ok, &orig, goal(&dest), &limits)?;
+    assert_eq!(path.len(), distance);
+    let path = Sqrid::astar_path_limits(sqrid::pos_dir_add_ok, &orig, &dest, &limits)?;
+    assert_eq!(path.len(), distance);
+    let path = Sqrid::ucs_path_limits(ucs_go, &orig, &dest, &limits)?;
+    assert_eq!(path.len(), distance);
+    // Maximum cost:
+    let limits = sqrid::SearchLimits {
+        max_cost: Some(distance - 1),
+        ..Default::default()
+    };
+    let Err(BudgetExceeded(from)) =
+        Sqrid::bfs_path_limits(sqrid::pos_dir_add_ok, &orig, goal(&dest), &limits)
+    else {
+        panic!("bfs budget not exceeded");
+    };
+    let from = *from;
+    for pos in Pos::iter() {
+        if orig.manhattan(&pos) >= distance {
+            assert!(from[pos].is_none());
+        } else if pos != orig {
+            // The partial map can be used to get the path to the visited positions:
+            let path = Sqrid::camefrom_into_path(from, &orig, &pos)?;
+            assert_eq!(path.len(), orig.manhattan(&pos));
+        }
+    }
+    assert!(matches!(
+        Sqrid::astar_path_limits(sqrid::pos_dir_add_ok, &orig, &dest, &limits),
+        Err(BudgetExceeded(_))
+    ));
+    assert!(matches!(
+        Sqrid::ucs_path_limits(ucs_go, &orig, &dest, &limits),
+        Err(BudgetExceeded(_))
+    ));
+    // Maximum number of expanded positions:
+    let limits = sqrid::SearchLimits {
+        max_expanded: Some(10),
+        ..Default::default()
+    };
+    let Err(BudgetExceeded(from)) = Sqrid::ucs_path_limits(ucs_go, &orig, &dest, &limits) else {
+        panic!("ucs budget not exceeded");
+    };
+    // The expanded positions and the one that was not expanded:
+    assert_eq!(from.iter().filter(|dir| dir.is_some()).count(), 11);
+    assert_eq!(from[orig], Some(Dir::default()));
+    // Cancellation is checked before each expansion:
+    let calls = std::cell::Cell::new(0);
+    let cancel = || {
+        calls.set(calls.get() + 1);
+        calls.get() > 3
+    };
+    let limits = sqrid::SearchLimits {
+        cancel: Some(&cancel),
+        ..Default::default()
+    };
+    let Err(BudgetExceeded(from)) =
+        Sqrid::astar_path_limits(sqrid::pos_dir_add_ok, &orig, &dest, &limits)
+    else {
+        panic!("astar not cancelled");
+    };
+    assert_eq!(calls.get(), 4);
+    assert_eq!(from.iter().filter(|dir| dir.is_some()).count(), 4);
+    Ok(())
+}
+
+#[test]
+fn test_limits_max_expanded() -> Result<()> {
+    use sqrid::LimitsError::BudgetExceeded;
+    let orig = Pos::TOP_LEFT;
+    let dest = Pos::BOTTOM_RIGHT;
+    let ucs_go = |pos: Pos, dir: Dir| Some(((pos + dir).ok()?, 1));
+    let limits = |max| sqrid::SearchLimits {
+        max_expanded: Some(max),
+        ..Default::default()
+    };
+    // Reaching the bottom-right corner requires expanding all other
+    // positions, including the origin:
+    let needed = Pos::SIZE - 1;
+    assert!(
+        Sqrid::bfs_path_limits(sqrid::pos_dir_add_ok, &orig, goal(&dest), &limits(needed)).is_ok()
+    );
+    assert!(matches!(
+        Sqrid::bfs_path_limits(
+            sqrid::pos_dir_add_ok,
+            &orig,
+            goal(&dest),
+            &limits(needed - 1)
+        ),
+        Err(BudgetExceeded(_))
+    ));
+    assert!(Sqrid::ucs_path_limits(ucs_go, &orig, &dest, &limits(needed)).is_ok());
+    assert!(matches!(
+        Sqrid::ucs_path_limits(ucs_go, &orig, &dest, &limits(needed - 1)),
+        Err(BudgetExceeded(_))
+    ));
+    // A* goes straight to a destination in the same line:
+    let dest = Pos::new(3, 0)?;
+    let path = Sqrid::astar_path_limits(sqrid::pos_dir_add_ok, &orig, &dest, &limits(3))?;
+    assert_eq!(path, vec![Dir::E; 3]);
+    assert!(matches!(
+        Sqrid::astar_path_limits(sqrid::pos_dir_add_ok, &orig, &dest, &limits(2)),
+        Err(BudgetExceeded(_))
+    ));
+    // Nothing is expanded with a limit of 0:
+    let Err(BudgetExceeded(from)) = Sqrid::ucs_path_limits(ucs_go, &orig, &dest, &limits(0)) else {
+        panic!("ucs budget not exceeded");
+    };
+    assert_eq!(from.iter().filter(|dir| dir.is_some()).count(), 1);
+    Ok(())
+}
+
+#[test]
+fn test_limits_into_error() -> Result<()> {
+    // Callers that use sqrid::Error get BudgetExceeded without the map:
+    fn search(max_cost: Cost) -> Result<Vec<Dir>, sqrid::Error> {
+        let limits = sqrid::SearchLimits {
+            max_cost: Some(max_cost),
+            ..Default::default()
+        };
+        let go = |pos: Pos, dir: Dir| Some(((pos + dir).ok()?, 1));
+        Ok(Sqrid::ucs_path_limits(
+            go,
+            &Pos::TOP_LEFT,
+            &Pos::BOTTOM_RIGHT,
+            &limits,
+        )?)
+    }
+    assert_eq!(
+        search(Pos::SIZE)?.len(),
+        Pos::TOP_LEFT.manhattan(&Pos::BOTTOM_RIGHT)
+    );
+    assert_eq!(search(3), Err(sqrid::Error::BudgetExceeded));
+    assert_eq!(
+        sqrid::Error::BudgetExceeded.to_string(),
+        "search budget exceeded"
+    );
+    Ok(())
+}
+
 #[test]
 fn test_bfs1() -> Result<()> {
     do_test(
@@ -333,3 +590,255 @@ fn test_bfs8() -> Result<()> {
         ],
     )
 }
+
+#[test]
+fn test_stats() -> Result<()> {
+    let orig = Pos::TOP_LEFT;
+    let dest = Pos::BOTTOM_RIGHT;
+    // Breadth-first: the observer gets the distance of each position
+    let mut order = Vec::new();
+    let mut it = Sqrid::bf_iter(sqrid::pos_dir_add_ok, &orig)
+        .with_stats()
+        .with_observer(|pos: Pos, distance| order.push((pos, distance)));
+    for _ in &mut it {}
+    let stats = *it.stats().unwrap();
+    drop(it);
+    assert_eq!(stats.expanded, Pos::SIZE);
+    assert_eq!(order.len(), Pos::SIZE);
+    for (pos, distance) in order {
+        assert_eq!(orig.manhattan(&pos), distance);
+    }
+    // UCS:
+    let mut expanded = 0;
+    let mut it = sqrid::ucs::UcsIterator::<
+        _,
+        GridUsize,
+        Pos,
+        false,
+        { Pos::SIZE.div_ceil(32) },
+        { Pos::SIZE },
+    >::new(|pos: Pos, dir: Dir| Some(((pos + dir).ok()?, 1)), &orig)
+    .with_stats()
+    .with_observer(|_: Pos, _| expanded += 1);
+    let count = it.by_ref().count();
+    let stats = *it.stats().unwrap();
+    drop(it);
+    assert_eq!(count, Pos::SIZE);
+    assert_eq!(stats.expanded, Pos::SIZE);
+    assert_eq!(expanded, Pos::SIZE);
+    assert_eq!(stats.repushed, 0);
+    assert!(stats.max_frontier > 0);
+    // A*: stats are not collected unless requested
+    let mut it = sqrid::astar::AstarIterator::<
+        _,
+        GridUsize,
+        Pos,
+        false,
+        { Pos::SIZE.div_ceil(32) },
+        { Pos::SIZE },
+    >::new(sqrid::pos_dir_add_ok, &orig, &dest);
+    assert!(it.by_ref().any(|(pos, _, _)| pos == dest));
+    assert!(it.stats().is_none());
+    let mut it = it.with_stats();
+    it.next();
+    assert_eq!(it.stats().unwrap().expanded, 1);
+    Ok(())
+}
+
+#[test]
+fn test_ucs_iter_costs() -> Result<()> {
+    type UcsIterator<F> = sqrid::ucs::UcsIterator<
+        F,
+        GridUsize,
+        Pos,
+        false,
+        { Pos::SIZE.div_ceil(32) },
+        { Pos::SIZE },
+    >;
+    let orig = Pos::TOP_LEFT;
+    // Costs that make positions be pushed more than once:
+    let digit = |pos: Pos, dir: Dir| {
+        let next = (pos + dir).ok()?;
+        Some((next, 1 + (pos.x() as usize * 7 + pos.y() as usize * 3) % 9))
+    };
+    let mut it = UcsIterator::new(digit, &orig).with_stats();
+    let mut seen = Gridbool::default();
+    let mut costs = GridUsize::default();
+    let mut last = 0;
+    let mut count = 0;
+    while let Some((pos, dir, cost)) = it.next() {
+        assert!(!seen.get(&pos), "{:?} yielded twice", pos);
+        seen.set_t(&pos);
+        assert!(cost >= last);
+        last = cost;
+        costs[pos] = cost;
+        if pos != orig {
+            let prev = (pos + dir)?;
+            assert_eq!(cost, it.cost(&prev) + digit(prev, -dir).unwrap().1);
+        }
+        count += 1;
+    }
+    assert_eq!(count, Pos::SIZE);
+    let stats = it.stats().unwrap();
+    assert_eq!(stats.expanded, Pos::SIZE);
+    assert!(stats.repushed > 0);
+    // All cells within a cost:
+    let near = UcsIterator::new(digit, &orig)
+        .take_while(|&(_, _, cost)| cost <= 10)
+        .count();
+    assert_eq!(near, costs.iter().filter(|&&cost| cost <= 10).count());
+    Ok(())
+}
+
+#[test]
+fn test_flow_field() -> Result<()> {
+    let goal = Pos::new(20, 7)?;
+    // Asymmetric costs, with a wall that isolates the first column:
+    let go = |pos: Pos, dir: Dir| {
+        let next = (pos + dir).ok()?;
+        if pos.x() == 0 || next.x() == 0 {
+            return None;
+        }
+        Some((next, 1 + (pos.x() as usize * 7 + pos.y() as usize * 3) % 9))
+    };
+    let go_cost = |pos, dir| go(pos, dir).unwrap().1;
+    let field = Sqrid::flow_field(go, &goal);
+    assert_eq!(field.costs[goal], 0);
+    assert_eq!(field.dirs[goal], None);
+    assert_eq!(field.follow(&goal)?, vec![]);
+    for pos in Pos::iter() {
+        if pos.x() == 0 {
+            assert!(!field.reaches(&pos));
+            assert_eq!(field.dirs[pos], None);
+            assert_eq!(
+                field.follow(&pos),
+                Err(sqrid::Error::DestinationUnreachable)
+            );
+            continue;
+        }
+        let path = field.follow(&pos)?;
+        let cost = path_cost(&pos, &path, go_cost)?;
+        assert_eq!(cost, field.costs[pos]);
+        let ucs = Sqrid::ucs_path(go, &pos, &goal)?;
+        assert_eq!(cost, path_cost(&pos, &ucs, go_cost)?);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_grid_boxed() -> Result<()> {
+    // The grids of this size don't fit in the stack of the test
+    // threads, and must be boxed by the search functions:
+    type SqridBig = sqrid::sqrid_create!(999, 999, false);
+    type PosBig = sqrid::pos_create!(SqridBig);
+    let go = |pos: PosBig, dir: Dir| (pos + dir).ok();
+    let orig = PosBig::new(500, 500)?;
+    let dest = PosBig::new(503, 498)?;
+    let (_, path) = SqridBig::bfs_path_grid(go, &orig, |pos| pos == dest)?;
+    assert_eq!(path.len(), 5);
+    let path = SqridBig::ucs_path_grid(|pos, dir| go(pos, dir).map(|p| (p, 1)), &orig, &dest)?;
+    assert_eq!(path.len(), 5);
+    let path = SqridBig::astar_path_grid(go, &orig, &dest)?;
+    assert_eq!(path.len(), 5);
+    Ok(())
+}
+
+type SqridHuge = sqrid::sqrid_create!(1000, 1000, false);
+type PosHuge = sqrid::pos_create!(SqridHuge);
+
+/// Run `f` in a thread with a 2MB stack, which can't hold the grids of
+/// [`SqridHuge`]
+fn run_small_stack<F>(f: F) -> Result<()>
+where
+    F: FnOnce() -> Result<()> + Send + 'static,
+{
+    std::thread::Builder::new()
+        .stack_size(2 * 1024 * 1024)
+        .spawn(f)?
+        .join()
+        .map_err(|_| anyhow!("thread panicked"))?
+}
+
+fn go_huge(pos: PosHuge, dir: Dir) -> Option<PosHuge> {
+    // Stay close to the center to keep the searches short:
+    let next = (pos + dir).ok()?;
+    let (x, y) = next.inner_tuple();
+    ((490..510).contains(&x) && (490..510).contains(&y)).then_some(next)
+}
+
+#[test]
+fn test_bfs_path_limits_boxed() -> Result<()> {
+    run_small_stack(|| {
+        let orig = PosHuge::new(500, 500)?;
+        let dest = PosHuge::new(503, 498)?;
+        let (_, path) = SqridHuge::bfs_path_limits(
+            go_huge,
+            &orig,
+            |pos| pos == dest,
+            &sqrid::SearchLimits::default(),
+        )
+        .map_err(|e| anyhow!("{}", e))?;
+        assert_eq!(path.len(), 5);
+        let limits = sqrid::SearchLimits {
+            max_cost: Some(2),
+            ..Default::default()
+        };
+        let result = SqridHuge::bfs_path_limits(go_huge, &orig, |pos| pos == dest, &limits);
+        let Err(sqrid::LimitsError::BudgetExceeded(from)) = result else {
+            return Err(anyhow!("budget should be exceeded"));
+        };
+        assert_eq!(from[PosHuge::new(502, 500)?], Some(Dir::W));
+        Ok(())
+    })
+}
+
+#[test]
+fn test_ucs_path_limits_boxed() -> Result<()> {
+    run_small_stack(|| {
+        let go = |pos, dir| go_huge(pos, dir).map(|p| (p, 1));
+        let orig = PosHuge::new(500, 500)?;
+        let dest = PosHuge::new(503, 498)?;
+        let path = SqridHuge::ucs_path_limits(go, &orig, &dest, &sqrid::SearchLimits::default())
+            .map_err(|e| anyhow!("{}", e))?;
+        assert_eq!(path.len(), 5);
+        let limits = sqrid::SearchLimits {
+            max_cost: Some(2),
+            ..Default::default()
+        };
+        let result = SqridHuge::ucs_path_limits(go, &orig, &dest, &limits);
+        assert!(matches!(result, Err(sqrid::LimitsError::BudgetExceeded(_))));
+        Ok(())
+    })
+}
+
+#[test]
+fn test_astar_path_limits_boxed() -> Result<()> {
+    run_small_stack(|| {
+        let orig = PosHuge::new(500, 500)?;
+        let dest = PosHuge::new(503, 498)?;
+        let path =
+            SqridHuge::astar_path_limits(go_huge, &orig, &dest, &sqrid::SearchLimits::default())
+                .map_err(|e| anyhow!("{}", e))?;
+        assert_eq!(path.len(), 5);
+        let limits = sqrid::SearchLimits {
+            max_expanded: Some(2),
+            ..Default::default()
+        };
+        let result = SqridHuge::astar_path_limits(go_huge, &orig, &dest, &limits);
+        assert!(matches!(result, Err(sqrid::LimitsError::BudgetExceeded(_))));
+        Ok(())
+    })
+}
+
+#[test]
+fn test_flow_field_boxed() -> Result<()> {
+    run_small_stack(|| {
+        let go = |pos, dir| go_huge(pos, dir).map(|p| (p, 1));
+        let goal = PosHuge::new(500, 500)?;
+        let field = SqridHuge::flow_field(go, &goal);
+        assert_eq!(field.costs[PosHuge::new(503, 498)?], 5);
+        assert!(!field.reaches(&PosHuge::TOP_LEFT));
+        assert_eq!(field.follow(&PosHuge::new(490, 490)?)?.len(), 20);
+        Ok(())
+    })
+}
@@ -0,0 +1,87 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![cfg(feature = "serde")]
+
+use sqrid::postrait::PosT;
+use sqrid::BoundedI8;
+use sqrid::Dir;
+
+use anyhow::Result;
+
+type Pos = sqrid::Pos<2, 1>;
+type Grid = sqrid::grid_create!(Pos, i32);
+type Gridbool = sqrid::gridbool_create!(Pos);
+type GridDir = sqrid::grid_create!(Pos, Option<Dir>);
+
+#[test]
+fn test_boundedint() -> Result<()> {
+    let b = BoundedI8::<-3, 5>::new(-2)?;
+    assert_eq!(serde_json::to_string(&b)?, "-2");
+    assert_eq!(serde_json::from_str::<BoundedI8<-3, 5>>("-2")?, b);
+    assert!(serde_json::from_str::<BoundedI8<-3, 5>>("6").is_err());
+    assert!(serde_json::from_str::<BoundedI8<-3, 5>>("-4").is_err());
+    Ok(())
+}
+
+#[test]
+fn test_pos() -> Result<()> {
+    let pos = Pos::new(2, 1)?;
+    assert_eq!(serde_json::to_string(&pos)?, "[2,1]");
+    assert_eq!(serde_json::from_str::<Pos>("[2,1]")?, pos);
+    for pos in Pos::iter() {
+        let json = serde_json::to_string(&pos)?;
+        assert_eq!(serde_json::from_str::<Pos>(&json)?, pos);
+    }
+    let err = serde_json::from_str::<Pos>("[3,1]").unwrap_err();
+    assert_eq!(err.to_string(), sqrid::Error::OutOfBounds.to_string());
+    assert!(serde_json::from_str::<Pos>("[2,2]").is_err());
+    assert!(serde_json::from_str::<Pos>("[-1,0]").is_err());
+    Ok(())
+}
+
+#[test]
+fn test_dir() -> Result<()> {
+    assert_eq!(serde_json::to_string(&Dir::NE)?, r#""NE""#);
+    for dir in Dir::iter::<true>() {
+        let json = serde_json::to_string(&dir)?;
+        assert_eq!(serde_json::from_str::<Dir>(&json)?, dir);
+    }
+    let err = serde_json::from_str::<Dir>(r#""X""#).unwrap_err();
+    assert_eq!(err.to_string(), sqrid::Error::DirParseError.to_string());
+    Ok(())
+}
+
+#[test]
+fn test_grid() -> Result<()> {
+    let grid = (0..6).collect::<Grid>();
+    let json = serde_json::to_string(&grid)?;
+    assert_eq!(json, "[[0,1,2],[3,4,5]]");
+    assert_eq!(serde_json::from_str::<Grid>(&json)?, grid);
+    let mut griddir = GridDir::default();
+    griddir[Pos::LAST] = Some(Dir::W);
+    let json = serde_json::to_string(&griddir)?;
+    assert_eq!(json, r#"[[null,null,null],[null,null,"W"]]"#);
+    assert_eq!(serde_json::from_str::<GridDir>(&json)?, griddir);
+    // Wrong dimensions:
+    assert!(serde_json::from_str::<Grid>("[[0,1,2]]").is_err());
+    assert!(serde_json::from_str::<Grid>("[[0,1,2],[3,4]]").is_err());
+    assert!(serde_json::from_str::<Grid>("[[0,1,2],[3,4,5,6]]").is_err());
+    assert!(serde_json::from_str::<Grid>("[[0,1,2],[3,4,5],[6,7,8]]").is_err());
+    Ok(())
+}
+
+#[test]
+fn test_gridbool() -> Result<()> {
+    let gb = Pos::iter()
+        .filter(|pos| pos.is_corner())
+        .collect::<Gridbool>();
+    let json = serde_json::to_string(&gb)?;
+    assert_eq!(json, r###"["#.#","#.#"]"###);
+    assert_eq!(serde_json::from_str::<Gridbool>(&json)?, gb);
+    assert!(serde_json::from_str::<Gridbool>(r##"["#.#"]"##).is_err());
+    assert!(serde_json::from_str::<Gridbool>(r###"["#.#","#.#."]"###).is_err());
+    assert!(serde_json::from_str::<Gridbool>(r###"["#.#","#x#"]"###).is_err());
+    Ok(())
+}
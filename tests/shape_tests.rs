@@ -0,0 +1,145 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use sqrid::postrait::PosT;
+use sqrid::Dir;
+use sqrid::Error;
+use sqrid::Shape;
+use sqrid::Symmetric;
+use sqrid::Symmetry;
+
+use anyhow::Result;
+
+type Sqrid = sqrid::sqrid_create!(9, 11, false);
+type Pos = sqrid::pos_create!(Sqrid);
+type Gridbool = sqrid::gridbool_create!(Sqrid);
+
+fn tetrominoes() -> Result<Vec<Shape>> {
+    Ok(vec![
+        Shape::new([(0, 0), (1, 0), (2, 0), (3, 0)])?,
+        Shape::new([(0, 0), (1, 0), (0, 1), (1, 1)])?,
+        Shape::new([(0, 0), (1, 0), (2, 0), (1, 1)])?,
+        Shape::new([(1, 0), (2, 0), (0, 1), (1, 1)])?,
+        Shape::new([(0, 0), (0, 1), (0, 2), (1, 2)])?,
+    ])
+}
+
+#[test]
+fn test_new() -> Result<()> {
+    let shape = Shape::new([(5, 3), (6, 3), (5, 4)])?;
+    assert_eq!((shape.width(), shape.height(), shape.len()), (2, 2, 3));
+    assert_eq!(
+        shape.cells().collect::<Vec<_>>(),
+        vec![(0, 0), (1, 0), (0, 1)]
+    );
+    assert_eq!(shape.to_string(), "##\n#.\n");
+    assert_eq!(Shape::new([]), Err(Error::Empty));
+    assert_eq!(Shape::new([(0, 0), (32, 0)]), Err(Error::OutOfBounds));
+    assert!(Shape::new([(0, 0), (31, 31)]).is_ok());
+    let mut gridbool = Gridbool::default();
+    gridbool.set_t(&Pos::new(3, 7)?);
+    gridbool.set_t(&Pos::new(4, 7)?);
+    gridbool.set_t(&Pos::new(3, 8)?);
+    assert_eq!(Shape::try_from(&gridbool)?, shape);
+    Ok(())
+}
+
+#[test]
+fn test_orientations() -> Result<()> {
+    let counts = tetrominoes()?
+        .iter()
+        .map(|shape| shape.orientations().len())
+        .collect::<Vec<_>>();
+    assert_eq!(counts, vec![2, 1, 4, 4, 8]);
+    // The 19 fixed tetrominoes, grouped in the 5 free ones:
+    let mut all = tetrominoes()?
+        .iter()
+        .flat_map(|shape| shape.orientations())
+        .collect::<Vec<_>>();
+    all.sort();
+    all.dedup();
+    assert_eq!(all.len(), 19);
+    let mut canonical = all
+        .iter()
+        .map(|shape| shape.canonical_form().0)
+        .collect::<Vec<_>>();
+    canonical.sort();
+    canonical.dedup();
+    assert_eq!(canonical.len(), 5);
+    let ell = Shape::new([(0, 0), (0, 1), (0, 2), (1, 2)])?;
+    assert_eq!(
+        Symmetry::RotateCw.apply(&ell),
+        Shape::new([(0, 0), (1, 0), (2, 0), (0, 1)])?
+    );
+    assert_eq!(
+        Symmetry::FlipH.apply(&ell),
+        Shape::new([(1, 0), (1, 1), (1, 2), (0, 2)])?
+    );
+    Ok(())
+}
+
+#[test]
+fn test_place() -> Result<()> {
+    // The 10-cells rows cross word boundaries at different offsets:
+    let mut gridbool = Gridbool::default();
+    let shapes = tetrominoes()?;
+    for shape in &shapes {
+        for pos in Pos::iter() {
+            let fits = shape.fits(&pos);
+            assert_eq!(shape.can_place(&gridbool, &pos), fits);
+            if !fits {
+                assert_eq!(shape.place(&mut gridbool, &pos), Err(Error::OutOfBounds));
+                assert_eq!(gridbool, Gridbool::default());
+                continue;
+            }
+            shape.place(&mut gridbool, &pos)?;
+            let expected = shape
+                .cells()
+                .map(|(x, y)| Pos::new(pos.x() + x, pos.y() + y).unwrap())
+                .collect::<Gridbool>();
+            assert_eq!(gridbool, expected);
+            assert!(!shape.can_place(&gridbool, &pos));
+            assert_eq!(shape.place(&mut gridbool, &pos), Err(Error::Collision));
+            shape.remove(&mut gridbool, &pos)?;
+            assert_eq!(gridbool, Gridbool::default());
+        }
+    }
+    // Shapes only collide with set positions they overlap:
+    let square = &shapes[1];
+    square.place(&mut gridbool, &Pos::new(4, 4)?)?;
+    assert!(square.can_place(&gridbool, &Pos::new(6, 4)?));
+    assert!(square.can_place(&gridbool, &Pos::new(4, 6)?));
+    assert!(!square.can_place(&gridbool, &Pos::new(5, 5)?));
+    assert!(!square.can_place(&gridbool, &Pos::new(3, 3)?));
+    Ok(())
+}
+
+#[test]
+fn test_drop() -> Result<()> {
+    let shapes = tetrominoes()?;
+    let (bar, square, tee) = (&shapes[0], &shapes[1], &shapes[2]);
+    let mut gridbool = Gridbool::default();
+    // Fill the bottom row, except for the last 2 columns:
+    for x in 0..2 {
+        let pos = bar.drop_until_collision(&gridbool, &Pos::new(4 * x, 0)?, Dir::S);
+        assert_eq!(pos, Some(Pos::new(4 * x, 11)?));
+        bar.place(&mut gridbool, &pos.unwrap())?;
+    }
+    let pos = tee.drop_until_collision(&gridbool, &Pos::new(2, 0)?, Dir::S);
+    assert_eq!(pos, Some(Pos::new(2, 9)?));
+    tee.place(&mut gridbool, &pos.unwrap())?;
+    // The square falls to the bottom in the last 2 columns:
+    let pos = square.drop_until_collision(&gridbool, &Pos::new(8, 0)?, Dir::S);
+    assert_eq!(pos, Some(Pos::new(8, 10)?));
+    // Sideways, until it hits the tee:
+    let pos = square.drop_until_collision(&gridbool, &Pos::new(8, 9)?, Dir::W);
+    assert_eq!(pos, Some(Pos::new(5, 9)?));
+    // Can't start at a collision:
+    let pos = square.drop_until_collision(&gridbool, &Pos::new(3, 9)?, Dir::W);
+    assert_eq!(pos, None);
+    // Diagonally, until it hits the edge:
+    let pos = square.drop_until_collision(&gridbool, &Pos::new(0, 0)?, Dir::SE);
+    assert_eq!(pos, Some(Pos::new(8, 8)?));
+    Ok(())
+}
@@ -0,0 +1,165 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use sqrid::postrait::PosT;
+use sqrid::Dir;
+use sqrid::Symmetric;
+use sqrid::Symmetry;
+
+use anyhow::Result;
+
+type Sqrid = sqrid::sqrid_create!(4, 4, false);
+type Pos = sqrid::pos_create!(Sqrid);
+type Grid = sqrid::grid_create!(Sqrid, u8);
+type Gridbool = sqrid::gridbool_create!(Sqrid);
+
+fn numbered() -> Grid {
+    Pos::iter().map(|pos| pos.to_usize() as u8).collect()
+}
+
+#[test]
+fn test_group() {
+    for s1 in Symmetry::ALL {
+        assert_eq!(s1.compose(&Symmetry::Identity), s1);
+        assert_eq!(Symmetry::Identity.compose(&s1), s1);
+        assert_eq!(s1.compose(&s1.inverse()), Symmetry::Identity);
+        assert_eq!(s1.inverse().compose(&s1), Symmetry::Identity);
+        for s2 in Symmetry::ALL {
+            for s3 in Symmetry::ALL {
+                assert_eq!(s1.compose(&s2).compose(&s3), s1.compose(&s2.compose(&s3)));
+            }
+        }
+    }
+    assert_eq!(
+        Symmetry::RotateCw.compose(&Symmetry::RotateCw),
+        Symmetry::Rotate180
+    );
+    assert_eq!(
+        Symmetry::FlipH.compose(&Symmetry::FlipV),
+        Symmetry::Rotate180
+    );
+    assert_eq!(
+        Symmetry::FlipH.compose(&Symmetry::RotateCc),
+        Symmetry::Transpose
+    );
+    assert_eq!(Symmetry::RotateCw.inverse(), Symmetry::RotateCc);
+}
+
+#[test]
+fn test_compose_apply() {
+    let grid = numbered();
+    for s1 in Symmetry::ALL {
+        for s2 in Symmetry::ALL {
+            let composed = s1.compose(&s2);
+            assert_eq!(composed.apply(&grid), s2.apply(&s1.apply(&grid)));
+            for pos in Pos::iter() {
+                assert_eq!(composed.apply(&pos), s2.apply(&s1.apply(&pos)));
+            }
+            for dir in Dir::ALL8 {
+                assert_eq!(composed.apply(&dir), s2.apply(&s1.apply(&dir)));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_grid() {
+    let grid = numbered();
+    let mut expected = grid;
+    expected.rotate_cw();
+    assert_eq!(Symmetry::RotateCw.apply(&grid), expected);
+    expected.rotate_cw();
+    assert_eq!(Symmetry::Rotate180.apply(&grid), expected);
+    expected.rotate_cw();
+    assert_eq!(Symmetry::RotateCc.apply(&grid), expected);
+    let mut expected = grid;
+    expected.flip_h();
+    assert_eq!(Symmetry::FlipH.apply(&grid), expected);
+    let mut expected = grid;
+    expected.flip_v();
+    assert_eq!(Symmetry::FlipV.apply(&grid), expected);
+    assert_eq!(Symmetry::Transpose.apply(&grid), grid.transpose());
+    // The grid and its transformation agree on positions and values:
+    for symmetry in Symmetry::ALL {
+        let transformed = symmetry.apply(&grid);
+        for pos in Pos::iter() {
+            assert_eq!(transformed[symmetry.apply(&pos)], grid[pos]);
+        }
+    }
+}
+
+#[test]
+fn test_dir_pos() -> Result<()> {
+    let pos = Pos::new(1, 0)?;
+    assert_eq!(Symmetry::Transpose.apply(&pos), Pos::new(0, 1)?);
+    assert_eq!(Symmetry::AntiTranspose.apply(&pos), Pos::new(4, 3)?);
+    assert_eq!(Symmetry::FlipV.apply(&pos), Pos::new(1, 4)?);
+    assert_eq!(Symmetry::RotateCw.apply(&Dir::N), Dir::E);
+    assert_eq!(Symmetry::RotateCc.apply(&Dir::NE), Dir::NW);
+    assert_eq!(Symmetry::FlipH.apply(&Dir::E), Dir::W);
+    assert_eq!(Symmetry::FlipV.apply(&Dir::NE), Dir::SE);
+    assert_eq!(Symmetry::AntiTranspose.apply(&Dir::N), Dir::E);
+    // Moving a transformed position in a transformed direction is
+    // the same as transforming the moved position:
+    for symmetry in Symmetry::ALL {
+        for pos in Pos::iter() {
+            for dir in Dir::ALL8 {
+                let moved = (pos + dir).ok().map(|p| symmetry.apply(&p));
+                let tmoved = (symmetry.apply(&pos) + symmetry.apply(&dir)).ok();
+                assert_eq!(moved, tmoved);
+            }
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_canonical_form() -> Result<()> {
+    let mut gridbool = Gridbool::default();
+    gridbool.set_t(&Pos::new(3, 1)?);
+    gridbool.set_t(&Pos::new(3, 2)?);
+    let (canonical, symmetry) = gridbool.canonical_form();
+    assert_eq!(symmetry.apply(&gridbool), canonical);
+    for s in Symmetry::ALL {
+        let transformed = s.apply(&gridbool);
+        assert_eq!(transformed.canonical_form().0, canonical);
+        assert_eq!(
+            Pos::iter()
+                .filter(|pos| transformed[pos])
+                .map(|pos| s.inverse().apply(&pos))
+                .collect::<Gridbool>(),
+            gridbool
+        );
+    }
+    let grid = numbered();
+    for s in Symmetry::ALL {
+        assert_eq!(s.apply(&grid).canonical_form(), (grid, s.inverse()));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_transpose_nonsquare() -> Result<()> {
+    type PosWide = sqrid::Pos<4, 1>;
+    type GridWide = sqrid::grid_create!(PosWide, u8);
+    type GridboolWide = sqrid::gridbool_create!(PosWide);
+    let grid = PosWide::iter()
+        .map(|pos| pos.to_usize() as u8)
+        .collect::<GridWide>();
+    let transposed = grid.transpose();
+    assert_eq!(transposed[sqrid::Pos::<1, 4>::new(1, 3)?], 8);
+    assert_eq!(transposed.transpose(), grid);
+    for pos in PosWide::iter() {
+        assert_eq!(transposed[pos.transpose()], grid[pos]);
+    }
+    let mut gridbool = GridboolWide::default();
+    gridbool.set_t(&PosWide::new(4, 0)?);
+    let transposed = gridbool.transpose();
+    assert_eq!(
+        transposed.iter_t().collect::<Vec<_>>(),
+        vec![sqrid::Pos::<1, 4>::new(0, 4)?]
+    );
+    assert_eq!(transposed.transpose(), gridbool);
+    Ok(())
+}